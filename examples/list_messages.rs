@@ -18,7 +18,7 @@ async fn main() -> sendly::Result<()> {
     println!("Count in page: {}", messages.len());
     println!();
 
-    for msg in messages.iter() {
+    for msg in &messages {
         println!("{}: {} - {}", msg.id, msg.to, msg.status);
     }
 
@@ -33,7 +33,7 @@ async fn main() -> sendly::Result<()> {
         ))
         .await?;
 
-    for msg in delivered.iter() {
+    for msg in &delivered {
         println!("{}: Delivered at {:?}", msg.id, msg.delivered_at);
     }
 
@@ -1,12 +1,46 @@
 //! Account resource for managing account information and credits.
 
 use crate::client::Sendly;
-use crate::error::Result;
+use crate::error::{unexpected_shape, Error, Result};
 use crate::models::{
-    Account, ApiKey, CreateApiKeyRequest, CreateApiKeyResponse, CreditTransactionList, Credits,
-    ListTransactionsOptions,
+    Account, ApiKey, CreateApiKeyRequest, CreateApiKeyResponse, CreditTransaction,
+    CreditTransactionList, Credits, Currency, ListTransactionsOptions, UpdateApiKeyRequest,
 };
+use regex::Regex;
 use serde::Deserialize;
+use std::sync::OnceLock;
+
+static DATE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn date_regex() -> &'static Regex {
+    DATE_REGEX.get_or_init(|| {
+        Regex::new(
+            r"^(\d{4})-(\d{2})-(\d{2})(T([01]\d|2[0-3]):[0-5]\d:[0-5]\d(\.\d+)?(Z|[+-]\d{2}:\d{2}))?$",
+        )
+        .unwrap()
+    })
+}
+
+/// Returns true if `value` is an RFC 3339 date or timestamp naming a real
+/// calendar day.
+fn is_valid_date(value: &str) -> bool {
+    let Some(caps) = date_regex().captures(value) else {
+        return false;
+    };
+    let year: u32 = caps[1].parse().unwrap_or(0);
+    let month: u32 = caps[2].parse().unwrap_or(0);
+    let day: u32 = caps[3].parse().unwrap_or(0);
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
 
 /// Account resource for managing account information and credits.
 pub struct AccountResource<'a> {
@@ -45,6 +79,8 @@ struct ApiKeyResponse {
     api_key: Option<ApiKey>,
     #[serde(default)]
     data: Option<ApiKey>,
+    #[serde(flatten)]
+    flat: Option<ApiKey>,
 }
 
 /// Usage statistics for an API key.
@@ -67,6 +103,73 @@ pub struct ApiKeyUsage {
     pub credits_used: i64,
 }
 
+/// Account-wide usage statistics for a billing period.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AccountUsage {
+    /// Number of messages sent.
+    #[serde(default, alias = "messagesSent")]
+    pub messages_sent: i64,
+    /// Credits spent.
+    #[serde(default, alias = "creditsUsed")]
+    pub credits_used: i64,
+    /// Number of messages delivered.
+    #[serde(default)]
+    pub delivered: i64,
+    /// Number of messages that failed.
+    #[serde(default)]
+    pub failed: i64,
+    /// Period the statistics cover.
+    #[serde(default)]
+    pub period: Option<UsagePeriod>,
+}
+
+impl AccountUsage {
+    /// Returns the share of sent messages that were delivered (0.0 to 1.0).
+    pub fn delivery_rate(&self) -> f64 {
+        if self.messages_sent == 0 {
+            return 0.0;
+        }
+        self.delivered as f64 / self.messages_sent as f64
+    }
+}
+
+/// Start and end of a usage period.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UsagePeriod {
+    /// Period start (RFC 3339).
+    #[serde(default)]
+    pub from: String,
+    /// Period end (RFC 3339).
+    #[serde(default)]
+    pub to: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AccountUsageResponse {
+    #[serde(default)]
+    usage: Option<AccountUsage>,
+    #[serde(default)]
+    data: Option<AccountUsage>,
+    #[serde(flatten)]
+    flat: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AccountUsageResponse {
+    /// Every `AccountUsage` field is optional, so an unwrapped body is only
+    /// taken as usage when it carries the message count.
+    fn into_usage(self) -> Result<AccountUsage> {
+        if let Some(usage) = self.usage.or(self.data) {
+            return Ok(usage);
+        }
+        if self.flat.contains_key("messagesSent") || self.flat.contains_key("messages_sent") {
+            return Ok(serde_json::from_value(serde_json::Value::Object(
+                self.flat,
+            ))?);
+        }
+        Err(unexpected_shape("usage report"))
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct ApiKeyUsageResponse {
     #[serde(default)]
@@ -133,15 +236,52 @@ impl<'a> AccountResource<'a> {
             .credits
             .or(result.data)
             .or(result.flat)
-            .unwrap_or_else(|| Credits {
+            .unwrap_or(Credits {
                 balance: 0,
                 available_balance: 0,
                 pending_credits: 0,
                 reserved_credits: 0,
-                currency: "USD".to_string(),
+                currency: Currency::Usd,
             }))
     }
 
+    /// Ensures the account has at least `required` credits available.
+    ///
+    /// Returns `Error::InsufficientCredits` if the available balance is too low,
+    /// so callers can check before starting a large send.
+    ///
+    /// # Arguments
+    ///
+    /// * `required` - Number of credits needed
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// client.account().ensure_credits(500).await?;
+    /// // Safe to start the campaign
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ensure_credits(&self, required: i64) -> Result<()> {
+        let credits = self.credits().await?;
+
+        if !credits.can_afford(required) {
+            return Err(Error::InsufficientCredits {
+                message: format!(
+                    "{} credits required, {} available",
+                    required, credits.available_balance
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Lists credit transactions.
     ///
     /// # Arguments
@@ -176,6 +316,67 @@ impl<'a> AccountResource<'a> {
         Ok(result)
     }
 
+    /// Iterates over all credit transactions with automatic pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options (filters are kept across pages)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    /// use futures::StreamExt;
+    /// use tokio::pin;
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    /// let account = client.account();
+    /// let stream = account.iter_transactions(None);
+    /// pin!(stream);
+    /// while let Some(result) = stream.next().await {
+    ///     let tx = result?;
+    ///     println!("{}: {} credits", tx.id, tx.amount);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_transactions(
+        &self,
+        options: Option<ListTransactionsOptions>,
+    ) -> impl futures::Stream<Item = Result<CreditTransaction>> + '_ {
+        let options = options.unwrap_or_default();
+        let mut offset = options.offset.unwrap_or(0);
+        let batch_size = options.limit.filter(|&l| l > 0).unwrap_or(100);
+        let transaction_type = options.transaction_type.clone();
+
+        async_stream::try_stream! {
+            loop {
+                let mut list_opts = ListTransactionsOptions::new()
+                    .limit(batch_size)
+                    .offset(offset);
+
+                if let Some(ref t) = transaction_type {
+                    list_opts = list_opts.transaction_type(t.clone());
+                }
+
+                let page = self.transactions(Some(list_opts)).await?;
+                let page_len = page.data.len();
+
+                for tx in page.data {
+                    yield tx;
+                }
+
+                // Stop if we got fewer results than requested
+                if page_len < batch_size as usize {
+                    break;
+                }
+
+                offset += batch_size;
+            }
+        }
+    }
+
     /// Lists API keys.
     ///
     /// # Example
@@ -215,7 +416,7 @@ impl<'a> AccountResource<'a> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     ///
     /// let response = client.account().create_api_key("Production").await?;
-    /// println!("New key: {}", response.key);
+    /// println!("New key: {}", response.key.expose());
     /// # Ok(())
     /// # }
     /// ```
@@ -264,6 +465,44 @@ impl<'a> AccountResource<'a> {
         Ok(result.api_key.or(result.data).unwrap_or_default())
     }
 
+    /// Updates an API key's name or expiration.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - API key ID
+    /// * `request` - Fields to update
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{Sendly, UpdateApiKeyRequest};
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let key = client.account().update_api_key(
+    ///     "key_abc123",
+    ///     UpdateApiKeyRequest::new().name("Staging"),
+    /// ).await?;
+    /// println!("Renamed to {}", key.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_api_key(
+        &self,
+        id: impl AsRef<str>,
+        request: UpdateApiKeyRequest,
+    ) -> Result<ApiKey> {
+        let path = format!("/account/keys/{}", id.as_ref());
+        let response = self.client.patch(&path, &request).await?;
+        let result: ApiKeyResponse = response.json().await?;
+        result
+            .api_key
+            .or(result.data)
+            .or(result.flat)
+            .ok_or_else(|| unexpected_shape("API key"))
+    }
+
     /// Gets usage statistics for a specific API key.
     ///
     /// # Arguments
@@ -290,6 +529,44 @@ impl<'a> AccountResource<'a> {
         Ok(result.usage.or(result.data).unwrap_or_default())
     }
 
+    /// Gets account-wide usage statistics for a date range.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Period start, as an RFC 3339 date or timestamp
+    /// * `to` - Period end, as an RFC 3339 date or timestamp
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let usage = client.account().usage("2025-01-01", "2025-01-31").await?;
+    /// println!("Sent: {}, delivery rate: {:.1}%", usage.messages_sent, usage.delivery_rate() * 100.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn usage(&self, from: &str, to: &str) -> Result<AccountUsage> {
+        for (name, value) in [("from", from), ("to", to)] {
+            if !is_valid_date(value) {
+                return Err(Error::Validation {
+                    message: format!("`{}` must be an RFC 3339 date or timestamp", name),
+                });
+            }
+        }
+
+        let query = vec![
+            ("from".to_string(), from.to_string()),
+            ("to".to_string(), to.to_string()),
+        ];
+        let response = self.client.get("/account/usage", &query).await?;
+        let result: AccountUsageResponse = response.json().await?;
+        result.into_usage()
+    }
+
     /// Revokes an API key.
     ///
     /// # Arguments
@@ -0,0 +1,121 @@
+//! Blocking (synchronous) Sendly client.
+//!
+//! Enabled with the `blocking` feature. Each client owns a current-thread
+//! Tokio runtime and drives the async client on it, so it must not be used
+//! from inside an async runtime.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use sendly::blocking::Sendly;
+//!
+//! fn main() -> Result<(), sendly::Error> {
+//!     let client = Sendly::new("sk_live_v1_your_api_key");
+//!
+//!     let message = client.messages().send_to("+15551234567", "Hello!")?;
+//!     println!("Message sent: {}", message.id);
+//!     Ok(())
+//! }
+//! ```
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::client::SendlyConfig;
+use crate::error::Result;
+use crate::models::{Credits, ListMessagesOptions, Message, MessageList, SendMessageRequest};
+
+/// Blocking Sendly API client.
+#[derive(Debug, Clone)]
+pub struct Sendly {
+    inner: crate::Sendly,
+    runtime: Arc<Runtime>,
+}
+
+impl Sendly {
+    /// Creates a new blocking client with default configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Your Sendly API key
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self::with_config(api_key, SendlyConfig::default())
+    }
+
+    /// Creates a new blocking client with custom configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Your Sendly API key
+    /// * `config` - Client configuration
+    pub fn with_config(api_key: impl Into<String>, config: SendlyConfig) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to build Tokio runtime");
+
+        Self {
+            inner: crate::Sendly::with_config(api_key, config),
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    /// Returns the Messages resource.
+    pub fn messages(&self) -> Messages<'_> {
+        Messages { client: self }
+    }
+
+    /// Returns the Account resource.
+    pub fn account(&self) -> AccountResource<'_> {
+        AccountResource { client: self }
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+/// Blocking Messages resource.
+#[derive(Debug, Clone)]
+pub struct Messages<'a> {
+    client: &'a Sendly,
+}
+
+impl Messages<'_> {
+    /// Sends an SMS message.
+    pub fn send(&self, request: SendMessageRequest) -> Result<Message> {
+        self.client
+            .block_on(self.client.inner.messages().send(request))
+    }
+
+    /// Sends an SMS message with simple parameters.
+    pub fn send_to(&self, to: impl Into<String>, text: impl Into<String>) -> Result<Message> {
+        self.client
+            .block_on(self.client.inner.messages().send_to(to, text))
+    }
+
+    /// Lists messages.
+    pub fn list(&self, options: Option<ListMessagesOptions>) -> Result<MessageList> {
+        self.client
+            .block_on(self.client.inner.messages().list(options))
+    }
+
+    /// Gets a message by ID.
+    pub fn get(&self, id: &str) -> Result<Message> {
+        self.client.block_on(self.client.inner.messages().get(id))
+    }
+}
+
+/// Blocking Account resource.
+pub struct AccountResource<'a> {
+    client: &'a Sendly,
+}
+
+impl AccountResource<'_> {
+    /// Gets current credit balance.
+    pub fn credits(&self) -> Result<Credits> {
+        self.client.block_on(self.client.inner.account().credits())
+    }
+}
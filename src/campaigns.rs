@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::client::Sendly;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::messages::validate_text;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -15,6 +16,45 @@ pub enum CampaignStatus {
     Failed,
 }
 
+impl CampaignStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CampaignStatus::Draft => "draft",
+            CampaignStatus::Scheduled => "scheduled",
+            CampaignStatus::Sending => "sending",
+            CampaignStatus::Sent => "sent",
+            CampaignStatus::Paused => "paused",
+            CampaignStatus::Cancelled => "cancelled",
+            CampaignStatus::Failed => "failed",
+        }
+    }
+}
+
+impl std::fmt::Display for CampaignStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for CampaignStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "draft" => Ok(CampaignStatus::Draft),
+            "scheduled" => Ok(CampaignStatus::Scheduled),
+            "sending" => Ok(CampaignStatus::Sending),
+            "sent" => Ok(CampaignStatus::Sent),
+            "paused" => Ok(CampaignStatus::Paused),
+            "cancelled" => Ok(CampaignStatus::Cancelled),
+            "failed" => Ok(CampaignStatus::Failed),
+            _ => Err(Error::Validation {
+                message: format!("Unknown campaign status: {}", s),
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Campaign {
     pub id: String,
@@ -78,6 +118,21 @@ pub struct CampaignPreview {
     pub warnings: Option<Vec<String>>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct RenderedRecipient {
+    pub phone: String,
+    pub text: String,
+    #[serde(default, alias = "contactId")]
+    pub contact_id: Option<String>,
+    #[serde(default)]
+    pub segments: Option<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RecipientPreviewResponse {
+    recipients: Vec<RenderedRecipient>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateCampaignRequest {
     pub name: String,
@@ -182,21 +237,17 @@ impl ListCampaignsOptions {
             params.push(("offset".to_string(), offset.to_string()));
         }
         if let Some(ref status) = self.status {
-            let status_str = match status {
-                CampaignStatus::Draft => "draft",
-                CampaignStatus::Scheduled => "scheduled",
-                CampaignStatus::Sending => "sending",
-                CampaignStatus::Sent => "sent",
-                CampaignStatus::Paused => "paused",
-                CampaignStatus::Cancelled => "cancelled",
-                CampaignStatus::Failed => "failed",
-            };
-            params.push(("status".to_string(), status_str.to_string()));
+            params.push(("status".to_string(), status.as_str().to_string()));
         }
         params
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct SendCampaignRequest {
+    rate: u32,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ScheduleCampaignRequest {
     #[serde(rename = "scheduled_at")]
@@ -240,6 +291,13 @@ impl<'a> CampaignsResource<'a> {
     }
 
     pub async fn create(&self, request: CreateCampaignRequest) -> Result<Campaign> {
+        if request.contact_list_ids.is_empty() {
+            return Err(Error::Validation {
+                message: "At least one contact list is required".to_string(),
+            });
+        }
+        validate_text(&request.text)?;
+
         let response = self.client.post("/campaigns", &request).await?;
         Ok(response.json().await?)
     }
@@ -265,6 +323,20 @@ impl<'a> CampaignsResource<'a> {
         Ok(response.json().await?)
     }
 
+    pub async fn preview_recipients(
+        &self,
+        id: &str,
+        sample_size: u32,
+    ) -> Result<Vec<RenderedRecipient>> {
+        let params = vec![("sample".to_string(), sample_size.to_string())];
+        let response = self
+            .client
+            .get(&format!("/campaigns/{}/preview/recipients", id), &params)
+            .await?;
+        let result: RecipientPreviewResponse = response.json().await?;
+        Ok(result.recipients)
+    }
+
     pub async fn send(&self, id: &str) -> Result<Campaign> {
         let response = self
             .client
@@ -273,6 +345,25 @@ impl<'a> CampaignsResource<'a> {
         Ok(response.json().await?)
     }
 
+    /// Sends a campaign at no more than `per_minute` messages per minute, to
+    /// stay under carrier throughput caps.
+    ///
+    /// The rate is enforced by the API; `per_minute` must be greater than zero.
+    pub async fn send_throttled(&self, id: &str, per_minute: u32) -> Result<Campaign> {
+        if per_minute == 0 {
+            return Err(Error::Validation {
+                message: "per_minute must be greater than zero".to_string(),
+            });
+        }
+
+        let request = SendCampaignRequest { rate: per_minute };
+        let response = self
+            .client
+            .post(&format!("/campaigns/{}/send", id), &request)
+            .await?;
+        Ok(response.json().await?)
+    }
+
     pub async fn schedule(&self, id: &str, request: ScheduleCampaignRequest) -> Result<Campaign> {
         let response = self
             .client
@@ -1,8 +1,11 @@
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::io::Write;
 
 use crate::client::Sendly;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::validate::{validate_metadata_with_limits, validate_phone};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Contact {
@@ -15,6 +18,8 @@ pub struct Contact {
     pub email: Option<String>,
     #[serde(default)]
     pub metadata: Option<HashMap<String, serde_json::Value>>,
+    #[serde(default)]
+    pub tags: Vec<String>,
     #[serde(default, alias = "createdAt")]
     pub created_at: Option<String>,
     #[serde(default, alias = "updatedAt")]
@@ -67,6 +72,8 @@ pub struct CreateContactRequest {
     pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 impl CreateContactRequest {
@@ -76,6 +83,7 @@ impl CreateContactRequest {
             name: None,
             email: None,
             metadata: None,
+            tags: Vec::new(),
         }
     }
 
@@ -93,6 +101,20 @@ impl CreateContactRequest {
         self.metadata = Some(metadata);
         self
     }
+
+    pub fn tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn add_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Default)]
@@ -105,6 +127,10 @@ pub struct UpdateContactRequest {
     pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, serde_json::Value>>,
+    /// Replaces the contact's tags. `Some(vec![])` clears them; `None`
+    /// leaves them unchanged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
 }
 
 impl UpdateContactRequest {
@@ -131,6 +157,44 @@ impl UpdateContactRequest {
         self.metadata = Some(metadata);
         self
     }
+
+    pub fn tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn add_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.get_or_insert_with(Vec::new).push(tag.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Upserted {
+    Created(Contact),
+    Updated(Contact),
+}
+
+impl Upserted {
+    pub fn contact(&self) -> &Contact {
+        match self {
+            Upserted::Created(contact) | Upserted::Updated(contact) => contact,
+        }
+    }
+
+    pub fn into_contact(self) -> Contact {
+        match self {
+            Upserted::Created(contact) | Upserted::Updated(contact) => contact,
+        }
+    }
+
+    pub fn was_created(&self) -> bool {
+        matches!(self, Upserted::Created(_))
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -139,6 +203,8 @@ pub struct ListContactsOptions {
     pub offset: Option<u32>,
     pub search: Option<String>,
     pub list_id: Option<String>,
+    /// Only return contacts that have every one of these tags.
+    pub tags: Vec<String>,
 }
 
 impl ListContactsOptions {
@@ -166,6 +232,11 @@ impl ListContactsOptions {
         self
     }
 
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
     pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
         let mut params = Vec::new();
         if let Some(limit) = self.limit {
@@ -180,6 +251,9 @@ impl ListContactsOptions {
         if let Some(ref list_id) = self.list_id {
             params.push(("list_id".to_string(), list_id.clone()));
         }
+        if !self.tags.is_empty() {
+            params.push(("tags".to_string(), self.tags.join(",")));
+        }
         params
     }
 }
@@ -313,17 +387,103 @@ impl<'a> ContactsResource<'a> {
         Ok(response.json().await?)
     }
 
+    /// Pages through every contact matching the filters, starting at
+    /// `options.offset`.
+    pub fn iter(
+        &self,
+        options: ListContactsOptions,
+    ) -> impl futures::Stream<Item = Result<Contact>> + '_ {
+        let batch_size = options.limit.filter(|&l| l > 0).unwrap_or(100);
+        let mut offset = options.offset.unwrap_or(0);
+
+        async_stream::try_stream! {
+            loop {
+                let page_opts = options.clone().limit(batch_size).offset(offset);
+                let page = self.list(page_opts).await?;
+                let page_len = page.contacts.len();
+
+                for contact in page.contacts {
+                    yield contact;
+                }
+
+                if page_len < batch_size as usize {
+                    break;
+                }
+
+                offset += batch_size;
+            }
+        }
+    }
+
+    /// Writes every contact matching the filters to `writer` as CSV, with a
+    /// header line. See [`Self::export_csv_with`].
+    pub async fn export_csv<W: Write>(
+        &self,
+        writer: W,
+        options: ListContactsOptions,
+    ) -> Result<u64> {
+        self.export_csv_with(writer, options, true).await
+    }
+
+    /// Writes every contact matching the filters to `writer` as CSV.
+    ///
+    /// Rows have `phone,name,email,tags` columns, with tags joined by `;`,
+    /// preceded by that header line when `header` is true. Returns the number
+    /// of contacts written, not counting the header.
+    pub async fn export_csv_with<W: Write>(
+        &self,
+        mut writer: W,
+        options: ListContactsOptions,
+        header: bool,
+    ) -> Result<u64> {
+        if header {
+            writeln!(writer, "phone,name,email,tags")?;
+        }
+
+        let contacts = self.iter(options);
+        futures::pin_mut!(contacts);
+
+        let mut rows = 0;
+        while let Some(contact) = contacts.next().await {
+            let contact = contact?;
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                csv_field(&contact.phone_number),
+                csv_field(contact.name.as_deref().unwrap_or_default()),
+                csv_field(contact.email.as_deref().unwrap_or_default()),
+                csv_field(&contact.tags.join(";")),
+            )?;
+            rows += 1;
+        }
+
+        writer.flush()?;
+        Ok(rows)
+    }
+
     pub async fn get(&self, id: &str) -> Result<Contact> {
         let response = self.client.get(&format!("/contacts/{}", id), &[]).await?;
         Ok(response.json().await?)
     }
 
     pub async fn create(&self, request: CreateContactRequest) -> Result<Contact> {
+        validate_phone(&request.phone_number)?;
+        if let Some(metadata) = &request.metadata {
+            validate_metadata_with_limits(metadata, &self.client.config().metadata_limits)?;
+        }
+
         let response = self.client.post("/contacts", &request).await?;
         Ok(response.json().await?)
     }
 
     pub async fn update(&self, id: &str, request: UpdateContactRequest) -> Result<Contact> {
+        if let Some(phone_number) = &request.phone_number {
+            validate_phone(phone_number)?;
+        }
+        if let Some(metadata) = &request.metadata {
+            validate_metadata_with_limits(metadata, &self.client.config().metadata_limits)?;
+        }
+
         let response = self
             .client
             .patch(&format!("/contacts/{}", id), &request)
@@ -336,12 +496,53 @@ impl<'a> ContactsResource<'a> {
         Ok(())
     }
 
+    /// Looks the contact up by exact phone number, then creates or updates it.
+    ///
+    /// Search matches are fuzzy, so every page of results is checked for an
+    /// exact match. Not atomic: a contact created concurrently may still
+    /// cause a conflict.
+    pub async fn upsert(&self, request: CreateContactRequest) -> Result<Upserted> {
+        let matches = self.iter(ListContactsOptions::new().search(request.phone_number.clone()));
+        futures::pin_mut!(matches);
+
+        let mut existing = None;
+        while let Some(contact) = matches.next().await {
+            let contact = contact?;
+            if contact.phone_number == request.phone_number {
+                existing = Some(contact);
+                break;
+            }
+        }
+
+        match existing {
+            Some(contact) => {
+                let update = UpdateContactRequest {
+                    phone_number: None,
+                    name: request.name,
+                    email: request.email,
+                    metadata: request.metadata,
+                    tags: (!request.tags.is_empty()).then_some(request.tags),
+                };
+                Ok(Upserted::Updated(self.update(&contact.id, update).await?))
+            }
+            None => Ok(Upserted::Created(self.create(request).await?)),
+        }
+    }
+
     pub async fn import(&self, request: ImportContactsRequest) -> Result<ImportContactsResponse> {
         let response = self.client.post("/contacts/import", &request).await?;
         Ok(response.json().await?)
     }
 }
 
+fn csv_field(value: &str) -> std::borrow::Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\"")).into()
+    } else {
+        value.into()
+    }
+}
+
 pub struct ContactListsResource<'a> {
     client: &'a Sendly,
 }
@@ -401,4 +602,29 @@ impl<'a> ContactListsResource<'a> {
             .await?;
         Ok(())
     }
+
+    /// Moves a contact from one list to another.
+    ///
+    /// Adds before removing, so a failure never drops the contact from both
+    /// lists. If only the removal fails, the contact is left in both and an
+    /// [`Error::PartialFailure`] says so.
+    pub async fn move_contact(
+        &self,
+        from_list: &str,
+        to_list: &str,
+        contact_id: &str,
+    ) -> Result<()> {
+        self.add_contacts(to_list, vec![contact_id.to_string()])
+            .await?;
+
+        self.remove_contact(from_list, contact_id)
+            .await
+            .map_err(|e| Error::PartialFailure {
+                step: format!(
+                    "Removing contact {} from list {} (already added to {})",
+                    contact_id, from_list, to_list
+                ),
+                source: Box::new(e),
+            })
+    }
 }
@@ -10,6 +10,10 @@ pub enum Error {
     #[error("Authentication failed: {message}")]
     Authentication { message: String },
 
+    /// API key lacks permission for this operation.
+    #[error("Forbidden: {message}")]
+    Forbidden { message: String },
+
     /// Rate limit exceeded.
     #[error("Rate limit exceeded: {message}")]
     RateLimit {
@@ -30,14 +34,26 @@ pub enum Error {
     #[error("Not found: {message}")]
     NotFound { message: String },
 
+    /// Request conflicts with the current state of the resource.
+    #[error("Conflict: {message}")]
+    Conflict { message: String },
+
     /// Network error.
     #[error("Network error: {message}")]
     Network { message: String },
 
+    /// Invalid client configuration, e.g. a malformed proxy URL.
+    #[error("Configuration error: {message}")]
+    Config { message: String },
+
     /// Request timeout.
     #[error("Request timed out")]
     Timeout,
 
+    /// The caller cancelled the request before it completed.
+    #[error("Request cancelled")]
+    Cancelled,
+
     /// JSON serialization/deserialization error.
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
@@ -46,6 +62,14 @@ pub enum Error {
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
 
+    /// I/O error, e.g. while writing an export.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Server-side failure (5xx).
+    #[error("Server error ({status_code}): {message}")]
+    ServerError { message: String, status_code: u16 },
+
     /// Generic API error.
     #[error("API error ({status_code}): {message}")]
     Api {
@@ -53,15 +77,55 @@ pub enum Error {
         status_code: u16,
         code: Option<String>,
     },
+
+    /// A multi-step operation failed after earlier steps had succeeded.
+    #[error("{step} failed after earlier steps succeeded: {source}")]
+    PartialFailure {
+        /// The step that failed.
+        step: String,
+        /// The error from that step.
+        source: Box<Error>,
+    },
 }
 
 impl Error {
     /// Returns true if this error is retryable.
+    ///
+    /// HTTP errors count when they are transient: timeouts, connect failures
+    /// and connections that broke while the request or response body was in
+    /// flight.
     pub fn is_retryable(&self) -> bool {
-        matches!(
-            self,
-            Error::RateLimit { .. } | Error::Network { .. } | Error::Timeout
-        )
+        match self {
+            Error::RateLimit { .. }
+            | Error::Network { .. }
+            | Error::Timeout
+            | Error::ServerError { .. } => true,
+            Error::Http(e) => is_transient(e),
+            _ => false,
+        }
+    }
+
+    /// Returns true if the request was rejected because of the caller (4xx).
+    pub fn is_client_error(&self) -> bool {
+        match self {
+            Error::Authentication { .. }
+            | Error::Forbidden { .. }
+            | Error::Validation { .. }
+            | Error::NotFound { .. }
+            | Error::InsufficientCredits { .. }
+            | Error::Conflict { .. } => true,
+            Error::Api { status_code, .. } => (400..500).contains(status_code),
+            _ => false,
+        }
+    }
+
+    /// Returns true if the server failed to handle the request (5xx).
+    pub fn is_server_error(&self) -> bool {
+        match self {
+            Error::ServerError { .. } => true,
+            Error::Api { status_code, .. } => (500..600).contains(status_code),
+            _ => false,
+        }
     }
 
     /// Returns the retry-after duration in seconds, if applicable.
@@ -73,19 +137,98 @@ impl Error {
     }
 }
 
+/// Returns true for transport errors worth retrying: timeouts, connect
+/// failures, and connections that broke mid-request or mid-body.
+pub(crate) fn is_transient(e: &reqwest::Error) -> bool {
+    e.is_timeout() || e.is_connect() || e.is_request() || e.is_body()
+}
+
+/// Error for a successful response whose body doesn't match any known shape.
+pub(crate) fn unexpected_shape(what: &str) -> Error {
+    Error::Json(serde::de::Error::custom(format!(
+        "response did not contain a {}",
+        what
+    )))
+}
+
+/// The error for a response body that grew past `max_response_bytes`.
+pub(crate) fn response_too_large(limit: usize) -> Error {
+    Error::Validation {
+        message: format!("Response too large (limit {} bytes)", limit),
+    }
+}
+
 /// API error response from the server.
 #[derive(Debug, serde::Deserialize)]
 pub(crate) struct ApiErrorResponse {
     pub message: Option<String>,
-    pub error: Option<String>,
+    pub error: Option<ApiErrorField>,
     pub code: Option<String>,
 }
 
+/// The `error` field, either a plain string or a nested `{message, code}`
+/// object (newer endpoints).
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ApiErrorField {
+    Message(String),
+    Detail {
+        message: Option<String>,
+        code: Option<String>,
+    },
+}
+
+/// Longest snippet of a non-JSON error body kept in the error message.
+const MAX_BODY_SNIPPET: usize = 200;
+
 impl ApiErrorResponse {
+    /// Parses an error body, keeping a snippet of it when it isn't JSON
+    /// (e.g. an HTML page from a gateway).
+    pub fn from_body(body: &str) -> Self {
+        let body = body.trim();
+        if body.is_empty() {
+            return Self::with_message("Empty response body".to_string());
+        }
+
+        serde_json::from_str(body).unwrap_or_else(|_| {
+            let snippet: String = body.chars().take(MAX_BODY_SNIPPET).collect();
+            let ellipsis = if snippet.len() < body.len() {
+                "..."
+            } else {
+                ""
+            };
+            Self::with_message(format!("Non-JSON response: {}{}", snippet, ellipsis))
+        })
+    }
+
+    fn with_message(message: String) -> Self {
+        Self {
+            message: Some(message),
+            error: None,
+            code: None,
+        }
+    }
+
     pub fn message(&self) -> String {
+        let error_message = match &self.error {
+            Some(ApiErrorField::Message(message)) => Some(message),
+            Some(ApiErrorField::Detail { message, .. }) => message.as_ref(),
+            None => None,
+        };
+
         self.message
-            .clone()
-            .or_else(|| self.error.clone())
+            .as_ref()
+            .or(error_message)
+            .cloned()
             .unwrap_or_else(|| "Unknown error".to_string())
     }
+
+    pub fn code(&self) -> Option<String> {
+        let error_code = match &self.error {
+            Some(ApiErrorField::Detail { code, .. }) => code.as_ref(),
+            _ => None,
+        };
+
+        self.code.as_ref().or(error_code).cloned()
+    }
 }
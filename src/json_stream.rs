@@ -0,0 +1,176 @@
+//! Incremental parsing of paginated list responses.
+//!
+//! List endpoints return `{"data": [...], ...}` (or `{"messages": [...]}`).
+//! Rather than buffering the whole body, [`data_items`] reads the response
+//! chunk by chunk and yields each element of the list as soon as it has
+//! been fully received, so memory use is bounded by the largest single item
+//! rather than the page size.
+
+use futures::Stream;
+use reqwest::Response;
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+use crate::error::{response_too_large, Error, Result};
+
+/// Streams the elements of the top-level `data` (or `messages`) array of a
+/// JSON response.
+///
+/// Keys before the array are skipped; anything after it is ignored, and an
+/// object without either key is an error. Reading more than `limit` bytes
+/// of the body (if set) fails with a "Response too large"
+/// [`Error::Validation`].
+pub(crate) fn data_items<T>(
+    response: Response,
+    limit: Option<usize>,
+) -> impl Stream<Item = Result<T>>
+where
+    T: DeserializeOwned,
+{
+    async_stream::try_stream! {
+        let mut reader = ChunkReader::new(response, limit);
+
+        reader.expect(b'{').await?;
+        let mut found = false;
+        while !reader.peek_is(b'}').await? {
+            let key: String = reader.next_value().await?;
+            reader.expect(b':').await?;
+
+            if key != "data" && key != "messages" {
+                reader.next_value::<IgnoredAny>().await?;
+                if reader.peek_is(b',').await? {
+                    reader.advance(1);
+                }
+                continue;
+            }
+
+            found = true;
+            reader.expect(b'[').await?;
+            if reader.peek_is(b']').await? {
+                break;
+            }
+            loop {
+                let item: T = reader.next_value().await?;
+                yield item;
+
+                if reader.peek_is(b',').await? {
+                    reader.advance(1);
+                } else {
+                    reader.expect(b']').await?;
+                    break;
+                }
+            }
+            break;
+        }
+
+        if !found {
+            Err(unexpected("object without a `data` or `messages` list"))?;
+        }
+    }
+}
+
+/// Buffers just enough of a response body to parse the next JSON value.
+struct ChunkReader {
+    response: Response,
+    buf: Vec<u8>,
+    pos: usize,
+    done: bool,
+    read: usize,
+    limit: Option<usize>,
+}
+
+impl ChunkReader {
+    fn new(response: Response, limit: Option<usize>) -> Self {
+        Self {
+            response,
+            buf: Vec::new(),
+            pos: 0,
+            done: false,
+            read: 0,
+            limit,
+        }
+    }
+
+    /// Reads the next chunk, dropping bytes that have already been consumed.
+    async fn fill(&mut self) -> Result<()> {
+        self.buf.drain(..self.pos);
+        self.pos = 0;
+
+        match self.response.chunk().await? {
+            Some(chunk) => {
+                self.read += chunk.len();
+                if let Some(limit) = self.limit.filter(|&limit| self.read > limit) {
+                    return Err(response_too_large(limit));
+                }
+                self.buf.extend_from_slice(&chunk);
+            }
+            None => self.done = true,
+        }
+        Ok(())
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.pos += n;
+    }
+
+    /// Skips whitespace and returns the next byte without consuming it.
+    async fn peek(&mut self) -> Result<u8> {
+        loop {
+            while let Some(&b) = self.buf.get(self.pos) {
+                if !b.is_ascii_whitespace() {
+                    return Ok(b);
+                }
+                self.pos += 1;
+            }
+            if self.done {
+                return Err(unexpected("end of response"));
+            }
+            self.fill().await?;
+        }
+    }
+
+    async fn peek_is(&mut self, byte: u8) -> Result<bool> {
+        Ok(self.peek().await? == byte)
+    }
+
+    async fn expect(&mut self, byte: u8) -> Result<()> {
+        let found = self.peek().await?;
+        if found != byte {
+            return Err(unexpected(&format!("byte {:?}", found as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Parses the next complete JSON value, reading more of the body as needed.
+    async fn next_value<T: DeserializeOwned>(&mut self) -> Result<T> {
+        self.peek().await?;
+
+        loop {
+            let mut values =
+                serde_json::Deserializer::from_slice(&self.buf[self.pos..]).into_iter::<T>();
+
+            match values.next() {
+                Some(Ok(value)) => {
+                    let end = self.pos + values.byte_offset();
+                    // A scalar ending exactly at the buffer edge may be truncated
+                    if end < self.buf.len() || self.done {
+                        self.pos = end;
+                        return Ok(value);
+                    }
+                }
+                Some(Err(e)) if !e.is_eof() || self.done => return Err(e.into()),
+                None if self.done => return Err(unexpected("end of response")),
+                _ => {}
+            }
+
+            self.fill().await?;
+        }
+    }
+}
+
+fn unexpected(what: &str) -> Error {
+    Error::Json(serde::de::Error::custom(format!(
+        "unexpected {} in list response",
+        what
+    )))
+}
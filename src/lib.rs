@@ -11,12 +11,9 @@
 //! async fn main() -> Result<(), sendly::Error> {
 //!     let client = Sendly::new("sk_live_v1_your_api_key");
 //!
-//!     let message = client.messages().send(SendMessageRequest {
-//!         to: "+15551234567".to_string(),
-//!         text: "Hello from Sendly!".to_string(),
-//!         message_type: None,
-//!         metadata: None,
-//!     }).await?;
+//!     let message = client.messages().send(
+//!         SendMessageRequest::new("+15551234567", "Hello from Sendly!")
+//!     ).await?;
 //!
 //!     println!("Message sent: {}", message.id);
 //!     Ok(())
@@ -38,7 +35,7 @@
 //!         vec!["message.delivered", "message.failed"],
 //!     ).await?;
 //!
-//!     println!("Webhook secret: {}", response.secret);
+//!     println!("Webhook secret: {}", response.secret.expose());
 //!     Ok(())
 //! }
 //! ```
@@ -63,21 +60,39 @@ mod campaigns;
 mod client;
 mod contacts;
 mod error;
+mod json_stream;
+mod lookup;
 mod messages;
 mod models;
+mod opt_outs;
 mod templates;
 mod verify;
 mod webhook_resource;
 
+pub mod validate;
 pub mod webhooks;
 
-pub use account_resource::AccountResource;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use account_resource::{AccountResource, AccountUsage, ApiKeyUsage, UsagePeriod};
 pub use campaigns::*;
-pub use client::{Sendly, SendlyConfig};
+pub use client::{RateLimitInfo, RequestOptions, ResponseMeta, Sendly, SendlyConfig};
 pub use contacts::*;
 pub use error::{Error, Result};
+pub use lookup::*;
 pub use messages::Messages;
 pub use models::*;
+pub use opt_outs::*;
 pub use templates::*;
 pub use verify::*;
 pub use webhook_resource::WebhooksResource;
+
+/// HTTP method type used by [`Sendly::request_raw`].
+pub use reqwest::Method;
+
+/// Token accepted by [`Messages::send_cancellable`].
+pub use tokio_util::sync::CancellationToken;
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::Sendly;
+use crate::error::Result;
+use crate::validate::validate_phone;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineType {
+    Mobile,
+    Landline,
+    Voip,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberLookup {
+    #[serde(alias = "phoneNumber")]
+    pub phone: String,
+    #[serde(default, alias = "lineType")]
+    pub line_type: LineType,
+    #[serde(default, alias = "carrierName")]
+    pub carrier_name: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default, alias = "isValid")]
+    pub is_valid: bool,
+}
+
+impl NumberLookup {
+    pub fn is_mobile(&self) -> bool {
+        self.line_type == LineType::Mobile
+    }
+
+    pub fn is_landline(&self) -> bool {
+        self.line_type == LineType::Landline
+    }
+}
+
+pub struct LookupResource<'a> {
+    client: &'a Sendly,
+}
+
+impl<'a> LookupResource<'a> {
+    pub fn new(client: &'a Sendly) -> Self {
+        Self { client }
+    }
+
+    pub async fn number(&self, phone: &str) -> Result<NumberLookup> {
+        validate_phone(phone)?;
+        let response = self
+            .client
+            .get(&format!("/lookup/{}", urlencoding::encode(phone)), &[])
+            .await?;
+        Ok(response.json().await?)
+    }
+}
@@ -1,23 +1,25 @@
-use regex::Regex;
-use std::sync::OnceLock;
+use futures::future::Either;
+use futures::StreamExt;
 
-use crate::client::Sendly;
-use crate::error::{Error, Result};
+use crate::client::{RequestOptions, ResponseMeta, Sendly};
+use crate::error::{unexpected_shape, Error, Result};
+use crate::json_stream;
 use crate::models::{
     BatchList, BatchMessageResponse, BatchPreviewResponse, CancelScheduledMessageResponse,
-    ListBatchesOptions, ListMessagesOptions, ListScheduledMessagesOptions, Message, MessageList,
-    ScheduleMessageRequest, ScheduledMessage, ScheduledMessageList, SendBatchRequest,
-    SendMessageRequest,
+    InboundMessage, InboundMessageList, ListBatchesOptions, ListInboundMessagesOptions,
+    ListMessagesOptions, ListScheduledMessagesOptions, Message, MessageEvent, MessageList,
+    MessagePreview, ScheduleMessageRequest, ScheduledMessage, ScheduledMessageList,
+    ScheduledMessageStatus, SendBatchRequest, SendMessageRequest, UpdateScheduledRequest,
 };
-
-static PHONE_REGEX: OnceLock<Regex> = OnceLock::new();
-
-fn phone_regex() -> &'static Regex {
-    PHONE_REGEX.get_or_init(|| Regex::new(r"^\+[1-9]\d{1,14}$").unwrap())
-}
+use crate::opt_outs::OptOutsResource;
+use crate::validate::{validate_metadata_with_limits, validate_phone};
+use tokio_util::sync::CancellationToken;
 
 const MAX_TEXT_LENGTH: usize = 1600;
 
+/// Maximum number of lookups issued concurrently by `get_many`.
+const GET_MANY_CONCURRENCY: usize = 5;
+
 /// Messages resource for sending and managing SMS.
 #[derive(Debug, Clone)]
 pub struct Messages<'a> {
@@ -29,7 +31,7 @@ impl<'a> Messages<'a> {
         Self { client }
     }
 
-    /// Sends an SMS message.
+    /// Sends a message, over SMS unless the request sets another channel.
     ///
     /// # Arguments
     ///
@@ -43,27 +45,113 @@ impl<'a> Messages<'a> {
     /// # async fn example() -> sendly::Result<()> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     ///
-    /// let message = client.messages().send(SendMessageRequest {
-    ///     to: "+15551234567".to_string(),
-    ///     text: "Hello from Sendly!".to_string(),
-    ///     message_type: None,
-    ///     metadata: None,
-    /// }).await?;
+    /// let message = client.messages().send(
+    ///     SendMessageRequest::new("+15551234567", "Hello from Sendly!")
+    /// ).await?;
     ///
     /// println!("Sent: {}", message.id);
     /// # Ok(())
     /// # }
     /// ```
     pub async fn send(&self, request: SendMessageRequest) -> Result<Message> {
+        self.send_with_options(request, RequestOptions::default())
+            .await
+    }
+
+    /// Sends an SMS message with per-request options.
+    ///
+    /// Setting an idempotency key lets the server deduplicate the send, which
+    /// makes it safe to retry after a connection error.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The send message request
+    /// * `options` - Per-request options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{RequestOptions, Sendly, SendMessageRequest};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let message = client.messages().send_with_options(
+    ///     SendMessageRequest::new("+15551234567", "Your order has shipped"),
+    ///     RequestOptions::new().idempotency_key("order_1234_shipped"),
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_with_options(
+        &self,
+        mut request: SendMessageRequest,
+        options: RequestOptions,
+    ) -> Result<Message> {
         validate_phone(&request.to)?;
-        validate_text(&request.text)?;
+        validate_message_text(&request)?;
+        self.validate_metadata(request.metadata.as_ref())?;
 
-        let response = self.client.post("/messages", &request).await?;
+        if self.client.config().check_opt_outs
+            && OptOutsResource::new(self.client).check(&request.to).await?
+        {
+            return Err(Error::Validation {
+                message: format!("Recipient {} has opted out", request.to),
+            });
+        }
+
+        request.from = self.resolve_from(request.from);
+
+        let response = self
+            .client
+            .post_with_options("/messages", &request, &options)
+            .await?;
         let message: Message = response.json().await?;
 
         Ok(message)
     }
 
+    /// Sends a message, aborting promptly if `cancel` fires.
+    ///
+    /// Cancellation also interrupts retry backoff. If the token fires after
+    /// the request reached the server, the message may still be sent; pair
+    /// this with an idempotency key when retrying.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The send message request
+    /// * `cancel` - Token that aborts the call with [`Error::Cancelled`]
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{CancellationToken, SendMessageRequest, Sendly};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    /// let cancel = CancellationToken::new();
+    ///
+    /// let message = client.messages().send_cancellable(
+    ///     SendMessageRequest::new("+15551234567", "Hello!"),
+    ///     cancel.child_token(),
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_cancellable(
+        &self,
+        request: SendMessageRequest,
+        cancel: CancellationToken,
+    ) -> Result<Message> {
+        let send = std::pin::pin!(self.send(request));
+        let cancelled = std::pin::pin!(cancel.cancelled());
+
+        match futures::future::select(send, cancelled).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(Error::Cancelled),
+        }
+    }
+
     /// Sends an SMS message with simple parameters.
     ///
     /// # Arguments
@@ -86,13 +174,43 @@ impl<'a> Messages<'a> {
     /// # }
     /// ```
     pub async fn send_to(&self, to: impl Into<String>, text: impl Into<String>) -> Result<Message> {
-        self.send(SendMessageRequest {
-            to: to.into(),
-            text: text.into(),
-            message_type: None,
-            metadata: None,
-        })
-        .await
+        self.send(SendMessageRequest::new(to, text)).await
+    }
+
+    /// Previews a message without sending it (dry run).
+    ///
+    /// Returns the segment count, credits needed and whether the account
+    /// balance covers the send.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The message to preview
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{Sendly, SendMessageRequest};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let preview = client.messages().preview(
+    ///     SendMessageRequest::new("+15551234567", "Hello from Sendly!")
+    /// ).await?;
+    ///
+    /// println!("This will cost {} credits", preview.credits_needed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preview(&self, request: SendMessageRequest) -> Result<MessagePreview> {
+        validate_phone(&request.to)?;
+        validate_message_text(&request)?;
+        self.validate_metadata(request.metadata.as_ref())?;
+
+        let response = self.client.post("/messages/preview", &request).await?;
+        let preview: MessagePreview = response.json().await?;
+
+        Ok(preview)
     }
 
     /// Lists messages.
@@ -130,6 +248,137 @@ impl<'a> Messages<'a> {
         Ok(result)
     }
 
+    /// Lists messages along with response metadata such as rate-limit headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let (messages, meta) = client.messages().list_with_meta(None).await?;
+    /// if let Some(rate_limit) = meta.rate_limit {
+    ///     println!("{} requests left", rate_limit.remaining);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_with_meta(
+        &self,
+        options: Option<ListMessagesOptions>,
+    ) -> Result<(MessageList, ResponseMeta)> {
+        let query = options.map(|o| o.to_query_params()).unwrap_or_default();
+
+        let response = self.client.get("/messages", &query).await?;
+        let meta = ResponseMeta::from_headers(response.headers());
+        let result: MessageList = response.json().await?;
+
+        Ok((result, meta))
+    }
+
+    /// Lists messages, yielding each one as soon as it has been received.
+    ///
+    /// Unlike [`Messages::list`], the page is parsed incrementally from the
+    /// response body instead of being buffered first, so memory use stays flat
+    /// regardless of page size. Only the messages of a single page are
+    /// returned; use [`Messages::iter`] to walk every page.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{ListMessagesOptions, Sendly};
+    /// use futures::StreamExt;
+    /// use tokio::pin;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    /// let messages = client.messages();
+    /// let stream = messages.list_streaming(Some(ListMessagesOptions::new().limit(100)));
+    /// pin!(stream);
+    /// while let Some(result) = stream.next().await {
+    ///     let message = result?;
+    ///     println!("{}: {}", message.id, message.to);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_streaming(
+        &self,
+        options: Option<ListMessagesOptions>,
+    ) -> impl futures::Stream<Item = Result<Message>> + '_ {
+        let query = options.map(|o| o.to_query_params()).unwrap_or_default();
+
+        async_stream::try_stream! {
+            let response = self.client.get_streaming("/messages", &query).await?;
+            let limit = self.client.config().max_response_bytes;
+            let items = json_stream::data_items::<Message>(response, limit);
+            futures::pin_mut!(items);
+
+            while let Some(message) = items.next().await {
+                yield message?;
+            }
+        }
+    }
+
+    /// Counts messages matching the filters without fetching any of them.
+    ///
+    /// Status, recipient and date filters apply as in [`Messages::list`];
+    /// `limit` and `offset` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{Sendly, ListMessagesOptions, MessageStatus};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let failed = client.messages().count(Some(
+    ///     ListMessagesOptions::new().status(MessageStatus::Failed)
+    /// )).await?;
+    /// println!("{} failed messages", failed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count(&self, options: Option<ListMessagesOptions>) -> Result<i64> {
+        #[derive(serde::Deserialize)]
+        struct CountResponse {
+            #[serde(default)]
+            count: Option<i64>,
+            #[serde(default)]
+            total: Option<i64>,
+        }
+
+        let mut options = options.unwrap_or_default();
+        options.limit = Some(0);
+        options.offset = None;
+
+        let response = self
+            .client
+            .get("/messages", &options.to_query_params())
+            .await?;
+        let result: CountResponse = response.json().await?;
+
+        result
+            .count
+            .or(result.total)
+            .ok_or_else(|| unexpected_shape("message count"))
+    }
+
     /// Gets a message by ID.
     ///
     /// # Arguments
@@ -144,90 +393,663 @@ impl<'a> Messages<'a> {
     /// # async fn example() -> sendly::Result<()> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     ///
-    /// let message = client.messages().get("msg_abc123").await?;
-    /// println!("Status: {}", message.status);
+    /// let message = client.messages().get("msg_abc123").await?;
+    /// println!("Status: {}", message.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: &str) -> Result<Message> {
+        if id.is_empty() {
+            return Err(Error::Validation {
+                message: "Message ID is required".to_string(),
+            });
+        }
+
+        // URL encode the ID to prevent path injection
+        let encoded_id = urlencoding::encode(id);
+        let path = format!("/messages/{}", encoded_id);
+        let response = self.client.get(&path, &[]).await?;
+        let message: Message = response.json().await?;
+
+        Ok(message)
+    }
+
+    /// Gets the delivery timeline of a message, in the order the API
+    /// returns it.
+    ///
+    /// Useful for diagnosing a message that seems stuck in one status.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Message ID
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// for event in client.messages().events("msg_abc123").await? {
+    ///     println!("{} at {}", event.status, event.timestamp);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn events(&self, id: &str) -> Result<Vec<MessageEvent>> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum EventsResponse {
+            List(Vec<MessageEvent>),
+            Wrapped {
+                #[serde(alias = "data")]
+                events: Vec<MessageEvent>,
+            },
+        }
+
+        if id.is_empty() {
+            return Err(Error::Validation {
+                message: "Message ID is required".to_string(),
+            });
+        }
+
+        let encoded_id = urlencoding::encode(id);
+        let path = format!("/messages/{}/events", encoded_id);
+        let response = self.client.get(&path, &[]).await?;
+        let events = match response.json().await? {
+            EventsResponse::List(events) => events,
+            EventsResponse::Wrapped { events } => events,
+        };
+
+        Ok(events)
+    }
+
+    /// Gets several messages by ID.
+    ///
+    /// Lookups run with bounded concurrency and each ID gets its own result,
+    /// so one missing message doesn't fail the whole call. Results are in the
+    /// same order as `ids`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - Message IDs
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let ids = vec!["msg_abc123".to_string(), "msg_def456".to_string()];
+    /// for (id, result) in ids.iter().zip(client.messages().get_many(&ids).await?) {
+    ///     match result {
+    ///         Ok(message) => println!("{}: {}", id, message.status),
+    ///         Err(e) => println!("{}: {}", id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_many(&self, ids: &[String]) -> Result<Vec<Result<Message>>> {
+        let results = futures::stream::iter(ids)
+            .map(|id| self.get(id))
+            .buffered(GET_MANY_CONCURRENCY)
+            .collect()
+            .await;
+
+        Ok(results)
+    }
+
+    /// Checks whether a message exists.
+    ///
+    /// Returns `Ok(false)` on a 404 and propagates any other error.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Message ID
+    pub async fn exists(&self, id: &str) -> Result<bool> {
+        found(self.get(id).await)
+    }
+
+    /// Cancels a queued message before it reaches the carrier.
+    ///
+    /// Returns the message with status [`MessageStatus::Cancelled`]. Fails with
+    /// [`Error::Conflict`] if the message has already been sent.
+    ///
+    /// [`MessageStatus::Cancelled`]: crate::MessageStatus::Cancelled
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Message ID
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{Error, Sendly};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// match client.messages().cancel("msg_abc123").await {
+    ///     Ok(message) => println!("Cancelled: {}", message.id),
+    ///     Err(Error::Conflict { .. }) => println!("Too late, already sent"),
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel(&self, id: &str) -> Result<Message> {
+        if id.is_empty() {
+            return Err(Error::Validation {
+                message: "Message ID is required".to_string(),
+            });
+        }
+
+        let encoded_id = urlencoding::encode(id);
+        let path = format!("/messages/{}/cancel", encoded_id);
+        let response = self.client.post(&path, &()).await?;
+        let message: Message = response.json().await?;
+
+        Ok(message)
+    }
+
+    /// Fetches one page of messages and the offset of the next page.
+    ///
+    /// The offset is `None` once [`MessageList::has_more`] reports no more
+    /// pages, so pagination can be driven with a plain loop, without
+    /// streams. The page size comes from `options.limit` (100 if unset) and
+    /// `options.offset` is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    /// * `offset` - Offset of the page to fetch
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let mut offset = Some(0);
+    /// while let Some(current) = offset {
+    ///     let (page, next) = client.messages().next_page(None, current).await?;
+    ///     for message in &page {
+    ///         println!("{}: {}", message.id, message.to);
+    ///     }
+    ///     offset = next;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn next_page(
+        &self,
+        options: Option<ListMessagesOptions>,
+        offset: u32,
+    ) -> Result<(MessageList, Option<u32>)> {
+        let options = options.unwrap_or_default();
+        // A zero page size would never advance, so treat it as the default
+        let batch_size = options.limit.filter(|&l| l > 0).unwrap_or(100);
+
+        // Keep every filter from the caller, only set the page
+        let mut page = self
+            .list(Some(options.limit(batch_size).offset(offset)))
+            .await?;
+
+        // Servers that don't echo the page fall back to what was requested,
+        // so `has_more` can tell whether the page came back full
+        page.limit = page.limit.or(Some(batch_size as i32));
+        page.offset = page.offset.or(Some(offset as i32));
+
+        // An empty page can't advance the offset, whatever the total says
+        let next = (page.has_more() && !page.is_empty()).then(|| offset + page.len() as u32);
+
+        Ok((page, next))
+    }
+
+    /// Iterates over all messages with automatic pagination.
+    ///
+    /// Built on [`next_page`](Self::next_page), which can be used directly
+    /// to page without streams.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    /// use futures::StreamExt;
+    /// use tokio::pin;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    /// let messages = client.messages();
+    /// let stream = messages.iter(None);
+    /// pin!(stream);
+    /// while let Some(result) = stream.next().await {
+    ///     let message = result?;
+    ///     println!("{}: {}", message.id, message.to);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(
+        &self,
+        options: Option<ListMessagesOptions>,
+    ) -> impl futures::Stream<Item = Result<Message>> + '_ {
+        let options = options.unwrap_or_default();
+        let mut offset = Some(options.offset.unwrap_or(0));
+
+        async_stream::try_stream! {
+            while let Some(current) = offset {
+                let (page, next) = self.next_page(Some(options.clone()), current).await?;
+                offset = next;
+
+                for message in page {
+                    yield message;
+                }
+            }
+        }
+    }
+
+    /// Iterates over all messages, fetching up to `buffer` pages ahead concurrently.
+    ///
+    /// Pages are requested by offset in parallel but messages are yielded in
+    /// order. Prefetching stops once a short page is seen or the reported total
+    /// is reached. A `buffer` of 0 or 1 behaves like [`Messages::iter`].
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    /// * `buffer` - Maximum number of pages in flight
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{ListMessagesOptions, Sendly};
+    /// use futures::StreamExt;
+    /// use tokio::pin;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    /// let messages = client.messages();
+    /// let stream = messages.iter_buffered(Some(ListMessagesOptions::new().limit(100)), 4);
+    /// pin!(stream);
+    /// while let Some(result) = stream.next().await {
+    ///     let message = result?;
+    ///     println!("{}: {}", message.id, message.to);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_buffered(
+        &self,
+        options: Option<ListMessagesOptions>,
+        buffer: usize,
+    ) -> impl futures::Stream<Item = Result<Message>> + '_ {
+        let options = options.unwrap_or_default();
+        let mut page_offset = options.offset.unwrap_or(0);
+        let batch_size = options.limit.filter(|&l| l > 0).unwrap_or(100);
+        let buffer = buffer.max(1);
+
+        async_stream::try_stream! {
+            let fetch = |offset| self.list(Some(options.clone().limit(batch_size).offset(offset)));
+            let mut next_offset = page_offset;
+            let mut in_flight = futures::stream::FuturesOrdered::new();
+
+            for _ in 0..buffer {
+                in_flight.push_back(fetch(next_offset));
+                next_offset += batch_size;
+            }
+
+            while let Some(page) = in_flight.next().await {
+                let page = page?;
+                let page_len = page.len();
+                let total = page.total();
+
+                for message in page {
+                    yield message;
+                }
+
+                // Stop on a short page or once the reported total is reached;
+                // any pages still in flight are dropped
+                if page_len < batch_size as usize {
+                    break;
+                }
+                if total > 0 && page_offset as usize + page_len >= total as usize {
+                    break;
+                }
+
+                page_offset += batch_size;
+
+                if total <= 0 || (next_offset as usize) < total as usize {
+                    in_flight.push_back(fetch(next_offset));
+                    next_offset += batch_size;
+                }
+            }
+        }
+    }
+}
+
+/// Validates each item of a batch: a valid recipient plus either its own
+/// text or template variables (which require a batch template).
+fn validate_batch_items(request: &SendBatchRequest) -> Result<()> {
+    for (i, msg) in request.messages.iter().enumerate() {
+        validate_phone(&msg.to).map_err(|_| Error::Validation {
+            message: format!("Invalid phone number at index {}", i),
+        })?;
+
+        if msg.variables.is_some() && msg.text.is_empty() {
+            if request.template_id.is_none() {
+                return Err(Error::Validation {
+                    message: format!("Template ID is required for variables at index {}", i),
+                });
+            }
+            continue;
+        }
+
+        validate_text(&msg.text).map_err(|_| Error::Validation {
+            message: format!("Invalid message text at index {}", i),
+        })?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn validate_text(text: &str) -> Result<()> {
+    if text.is_empty() {
+        return Err(Error::Validation {
+            message: "Message text is required".to_string(),
+        });
+    }
+    if text.len() > MAX_TEXT_LENGTH {
+        return Err(Error::Validation {
+            message: format!(
+                "Message text exceeds maximum length ({} characters)",
+                MAX_TEXT_LENGTH
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Rejects an empty send time and, with the `chrono` feature, one that has
+/// already passed. Unparseable times are left for the API to reject.
+fn validate_future(scheduled_at: &str) -> Result<()> {
+    if scheduled_at.is_empty() {
+        return Err(Error::Validation {
+            message: "scheduled_at is required".to_string(),
+        });
+    }
+
+    #[cfg(feature = "chrono")]
+    if let Ok(when) = chrono::DateTime::parse_from_rfc3339(scheduled_at) {
+        if when <= chrono::Utc::now() {
+            return Err(Error::Validation {
+                message: format!("Scheduled time {} is not in the future", scheduled_at),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Rejects a time window whose start is after its end.
+///
+/// With the `chrono` feature both timestamps are parsed, so differing UTC
+/// offsets and precisions compare correctly. Otherwise only UTC (`Z`)
+/// timestamps of the same shape are compared, as strings; anything else is
+/// left for the API to check.
+fn validate_time_window(after: &str, before: &str) -> Result<()> {
+    let same_shape_utc =
+        after.ends_with('Z') && before.ends_with('Z') && after.len() == before.len();
+
+    #[cfg(feature = "chrono")]
+    let inverted = match (
+        chrono::DateTime::parse_from_rfc3339(after),
+        chrono::DateTime::parse_from_rfc3339(before),
+    ) {
+        (Ok(after), Ok(before)) => after > before,
+        _ => same_shape_utc && after > before,
+    };
+    #[cfg(not(feature = "chrono"))]
+    let inverted = same_shape_utc && after > before;
+
+    if inverted {
+        return Err(Error::Validation {
+            message: format!(
+                "scheduledAfter ({}) must not be later than scheduledBefore ({})",
+                after, before
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// SMS is limited by segment count, including as a fallback channel; other
+/// channels only need non-empty text.
+fn validate_message_text(request: &SendMessageRequest) -> Result<()> {
+    if request.may_use_sms() {
+        validate_text(&request.text)
+    } else if request.text.is_empty() {
+        Err(Error::Validation {
+            message: "Message text is required".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Maps a lookup result to whether the resource exists.
+fn found<T>(result: Result<T>) -> Result<bool> {
+    match result {
+        Ok(_) => Ok(true),
+        Err(Error::NotFound { .. }) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+// ==================== Schedule Methods ====================
+
+impl<'a> Messages<'a> {
+    /// Schedules an SMS message for future delivery.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The schedule message request
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{MessageType, Sendly, ScheduleMessageRequest};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let scheduled = client.messages().schedule(
+    ///     ScheduleMessageRequest::new(
+    ///         "+15551234567",
+    ///         "Reminder: Your appointment is tomorrow!",
+    ///         "2025-01-20T10:00:00Z",
+    ///     )
+    ///     .message_type(MessageType::Transactional)
+    /// ).await?;
+    ///
+    /// println!("Scheduled: {}", scheduled.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn schedule(&self, mut request: ScheduleMessageRequest) -> Result<ScheduledMessage> {
+        validate_phone(&request.to)?;
+        validate_text(&request.text)?;
+        self.validate_metadata(request.metadata.as_ref())?;
+
+        if request.scheduled_at.is_empty() {
+            return Err(Error::Validation {
+                message: "scheduled_at is required".to_string(),
+            });
+        }
+
+        request.from = self.resolve_from(request.from);
+
+        let response = self.client.post("/messages/schedule", &request).await?;
+        let scheduled: ScheduledMessage = response.json().await?;
+
+        Ok(scheduled)
+    }
+
+    /// Schedules a message to be sent at a given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Recipient phone number in E.164 format
+    /// * `text` - Message content
+    /// * `when` - When to send; must be in the future
+    #[cfg(feature = "chrono")]
+    pub async fn schedule_at(
+        &self,
+        to: impl Into<String>,
+        text: impl Into<String>,
+        when: chrono::DateTime<chrono::Utc>,
+    ) -> Result<ScheduledMessage> {
+        if when <= chrono::Utc::now() {
+            return Err(Error::Validation {
+                message: format!("Scheduled time {} is not in the future", when.to_rfc3339()),
+            });
+        }
+
+        self.schedule(ScheduleMessageRequest::new(to, text, when.to_rfc3339()))
+            .await
+    }
+
+    /// Schedules a message to be sent after a delay.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Recipient phone number in E.164 format
+    /// * `text` - Message content
+    /// * `delay` - How long from now to send; must be positive
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let scheduled = client
+    ///     .messages()
+    ///     .schedule_in("+15551234567", "Your table is ready", chrono::Duration::minutes(30))
+    ///     .await?;
+    /// println!("Will send at {}", scheduled.scheduled_at);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub async fn schedule_in(
+        &self,
+        to: impl Into<String>,
+        text: impl Into<String>,
+        delay: chrono::Duration,
+    ) -> Result<ScheduledMessage> {
+        self.schedule_at(to, text, chrono::Utc::now() + delay).await
+    }
+
+    /// Lists scheduled messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{Sendly, ListScheduledMessagesOptions};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let scheduled = client.messages().list_scheduled(None).await?;
+    /// for msg in scheduled {
+    ///     println!("{}: {}", msg.id, msg.scheduled_at);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get(&self, id: &str) -> Result<Message> {
-        if id.is_empty() {
-            return Err(Error::Validation {
-                message: "Message ID is required".to_string(),
-            });
+    pub async fn list_scheduled(
+        &self,
+        options: Option<ListScheduledMessagesOptions>,
+    ) -> Result<ScheduledMessageList> {
+        if let Some(ref options) = options {
+            if let Some(ref to) = options.to {
+                validate_phone(to)?;
+            }
+            if let (Some(after), Some(before)) =
+                (&options.scheduled_after, &options.scheduled_before)
+            {
+                validate_time_window(after, before)?;
+            }
         }
 
-        // URL encode the ID to prevent path injection
-        let encoded_id = urlencoding::encode(id);
-        let path = format!("/messages/{}", encoded_id);
-        let response = self.client.get(&path, &[]).await?;
-        let message: Message = response.json().await?;
+        let query = options.map(|o| o.to_query_params()).unwrap_or_default();
 
-        Ok(message)
+        let response = self.client.get("/messages/scheduled", &query).await?;
+        let result: ScheduledMessageList = response.json().await?;
+
+        Ok(result)
     }
 
-    /// Iterates over all messages with automatic pagination.
+    /// Iterates over all scheduled messages with automatic pagination.
     ///
     /// # Arguments
     ///
-    /// * `options` - Optional query options
+    /// * `options` - Optional query options (filters are kept across pages)
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// use sendly::Sendly;
+    /// use sendly::{ListScheduledMessagesOptions, Sendly};
     /// use futures::StreamExt;
     /// use tokio::pin;
     ///
     /// # async fn example() -> sendly::Result<()> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     /// let messages = client.messages();
-    /// let stream = messages.iter(None);
+    /// let stream = messages.iter_scheduled(Some(
+    ///     ListScheduledMessagesOptions::new().to("+15551234567"),
+    /// ));
     /// pin!(stream);
     /// while let Some(result) = stream.next().await {
-    ///     let message = result?;
-    ///     println!("{}: {}", message.id, message.to);
+    ///     messages.cancel_scheduled(&result?.id).await?;
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub fn iter(
+    pub fn iter_scheduled(
         &self,
-        options: Option<ListMessagesOptions>,
-    ) -> impl futures::Stream<Item = Result<Message>> + '_ {
+        options: Option<ListScheduledMessagesOptions>,
+    ) -> impl futures::Stream<Item = Result<ScheduledMessage>> + '_ {
         let options = options.unwrap_or_default();
         let mut offset = options.offset.unwrap_or(0);
-        let batch_size = options.limit.unwrap_or(100);
-        let status = options.status.clone();
-        let to = options.to.clone();
+        let batch_size = options.limit.filter(|&l| l > 0).unwrap_or(100);
 
         async_stream::try_stream! {
             loop {
-                let mut list_opts = ListMessagesOptions::new()
-                    .limit(batch_size)
-                    .offset(offset);
-
-                // Only apply filters if specified
-                if let Some(ref s) = status {
-                    list_opts = list_opts.status(s.clone());
-                }
-                if let Some(ref t) = to {
-                    list_opts = list_opts.to(t.clone());
-                }
-
-                let page = self.list(Some(list_opts)).await;
-
-                let page = match page {
-                    Ok(p) => p,
-                    Err(e) => {
-                        Err(e)?;
-                        return;
-                    }
-                };
+                let list_opts = options.clone().limit(batch_size).offset(offset);
 
+                let page = self.list_scheduled(Some(list_opts)).await?;
                 let page_len = page.len();
 
-                for message in page {
-                    yield message;
+                for scheduled in page {
+                    yield scheduled;
                 }
 
                 // Stop if we got fewer results than requested
@@ -239,115 +1061,111 @@ impl<'a> Messages<'a> {
             }
         }
     }
-}
-
-fn validate_phone(phone: &str) -> Result<()> {
-    if !phone_regex().is_match(phone) {
-        return Err(Error::Validation {
-            message: "Invalid phone number format. Use E.164 format (e.g., +15551234567)"
-                .to_string(),
-        });
-    }
-    Ok(())
-}
-
-fn validate_text(text: &str) -> Result<()> {
-    if text.is_empty() {
-        return Err(Error::Validation {
-            message: "Message text is required".to_string(),
-        });
-    }
-    if text.len() > MAX_TEXT_LENGTH {
-        return Err(Error::Validation {
-            message: format!(
-                "Message text exceeds maximum length ({} characters)",
-                MAX_TEXT_LENGTH
-            ),
-        });
-    }
-    Ok(())
-}
-
-// ==================== Schedule Methods ====================
 
-impl<'a> Messages<'a> {
-    /// Schedules an SMS message for future delivery.
+    /// Gets a scheduled message by ID.
     ///
     /// # Arguments
     ///
-    /// * `request` - The schedule message request
+    /// * `id` - Scheduled message ID
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// use sendly::{Sendly, ScheduleMessageRequest};
+    /// use sendly::Sendly;
     ///
     /// # async fn example() -> sendly::Result<()> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     ///
-    /// let scheduled = client.messages().schedule(ScheduleMessageRequest {
-    ///     to: "+15551234567".to_string(),
-    ///     text: "Reminder: Your appointment is tomorrow!".to_string(),
-    ///     scheduled_at: "2025-01-20T10:00:00Z".to_string(),
-    ///     from: None,
-    ///     message_type: None,
-    ///     metadata: None,
-    /// }).await?;
-    ///
-    /// println!("Scheduled: {}", scheduled.id);
+    /// let scheduled = client.messages().get_scheduled("sched_abc123").await?;
+    /// println!("Status: {:?}", scheduled.status);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn schedule(&self, request: ScheduleMessageRequest) -> Result<ScheduledMessage> {
-        validate_phone(&request.to)?;
-        validate_text(&request.text)?;
-
-        if request.scheduled_at.is_empty() {
+    pub async fn get_scheduled(&self, id: &str) -> Result<ScheduledMessage> {
+        if id.is_empty() {
             return Err(Error::Validation {
-                message: "scheduled_at is required".to_string(),
+                message: "Scheduled message ID is required".to_string(),
             });
         }
 
-        let response = self.client.post("/messages/schedule", &request).await?;
+        let encoded_id = urlencoding::encode(id);
+        let path = format!("/messages/scheduled/{}", encoded_id);
+        let response = self.client.get(&path, &[]).await?;
         let scheduled: ScheduledMessage = response.json().await?;
 
         Ok(scheduled)
     }
 
-    /// Lists scheduled messages.
+    /// Checks whether a scheduled message exists.
+    ///
+    /// Returns `Ok(false)` on a 404 and propagates any other error.
     ///
     /// # Arguments
     ///
-    /// * `options` - Optional query options
+    /// * `id` - Scheduled message ID
+    pub async fn scheduled_exists(&self, id: &str) -> Result<bool> {
+        found(self.get_scheduled(id).await)
+    }
+
+    /// Edits a scheduled message before it is sent.
+    ///
+    /// The credit reservation is kept, unlike cancelling and rescheduling.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Scheduled message ID
+    /// * `request` - Fields to change; at least one must be set
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// use sendly::{Sendly, ListScheduledMessagesOptions};
+    /// use sendly::{Sendly, UpdateScheduledRequest};
     ///
     /// # async fn example() -> sendly::Result<()> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     ///
-    /// let scheduled = client.messages().list_scheduled(None).await?;
-    /// for msg in scheduled {
-    ///     println!("{}: {}", msg.id, msg.scheduled_at);
-    /// }
+    /// let scheduled = client.messages().update_scheduled(
+    ///     "sched_abc123",
+    ///     UpdateScheduledRequest::new().text("Your appointment is at 3pm"),
+    /// ).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_scheduled(
+    pub async fn update_scheduled(
         &self,
-        options: Option<ListScheduledMessagesOptions>,
-    ) -> Result<ScheduledMessageList> {
-        let query = options.map(|o| o.to_query_params()).unwrap_or_default();
+        id: &str,
+        request: UpdateScheduledRequest,
+    ) -> Result<ScheduledMessage> {
+        if id.is_empty() {
+            return Err(Error::Validation {
+                message: "Scheduled message ID is required".to_string(),
+            });
+        }
+        if request.text.is_none() && request.scheduled_at.is_none() {
+            return Err(Error::Validation {
+                message: "Nothing to update: set text or scheduled_at".to_string(),
+            });
+        }
+        if let Some(ref text) = request.text {
+            validate_text(text)?;
+        }
+        if let Some(ref scheduled_at) = request.scheduled_at {
+            validate_future(scheduled_at)?;
+        }
 
-        let response = self.client.get("/messages/scheduled", &query).await?;
-        let result: ScheduledMessageList = response.json().await?;
+        let encoded_id = urlencoding::encode(id);
+        let path = format!("/messages/scheduled/{}", encoded_id);
+        let response = self.client.patch(&path, &request).await?;
+        let scheduled: ScheduledMessage = response.json().await?;
 
-        Ok(result)
+        Ok(scheduled)
     }
 
-    /// Gets a scheduled message by ID.
+    /// Returns the credits that cancelling a scheduled message would refund,
+    /// without cancelling it.
+    ///
+    /// A pending message would refund all of its reserved credits; one that
+    /// was already sent, cancelled or failed would refund nothing.
     ///
     /// # Arguments
     ///
@@ -361,24 +1179,18 @@ impl<'a> Messages<'a> {
     /// # async fn example() -> sendly::Result<()> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     ///
-    /// let scheduled = client.messages().get_scheduled("sched_abc123").await?;
-    /// println!("Status: {:?}", scheduled.status);
+    /// let refund = client.messages().preview_cancel_scheduled("sched_abc123").await?;
+    /// println!("Cancelling would refund {} credits", refund);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_scheduled(&self, id: &str) -> Result<ScheduledMessage> {
-        if id.is_empty() {
-            return Err(Error::Validation {
-                message: "Scheduled message ID is required".to_string(),
-            });
-        }
-
-        let encoded_id = urlencoding::encode(id);
-        let path = format!("/messages/scheduled/{}", encoded_id);
-        let response = self.client.get(&path, &[]).await?;
-        let scheduled: ScheduledMessage = response.json().await?;
+    pub async fn preview_cancel_scheduled(&self, id: &str) -> Result<i32> {
+        let scheduled = self.get_scheduled(id).await?;
 
-        Ok(scheduled)
+        Ok(match scheduled.status {
+            ScheduledMessageStatus::Scheduled => scheduled.credits_reserved,
+            _ => 0,
+        })
     }
 
     /// Cancels a scheduled message.
@@ -431,44 +1243,54 @@ impl<'a> Messages<'a> {
     /// # async fn example() -> sendly::Result<()> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     ///
-    /// let result = client.messages().send_batch(SendBatchRequest {
-    ///     messages: vec![
-    ///         BatchMessageItem {
-    ///             to: "+15551234567".to_string(),
-    ///             text: "Hello Alice!".to_string(),
-    ///             metadata: None,
-    ///         },
-    ///         BatchMessageItem {
-    ///             to: "+15559876543".to_string(),
-    ///             text: "Hello Bob!".to_string(),
-    ///             metadata: None,
-    ///         },
-    ///     ],
-    ///     from: None,
-    ///     message_type: None,
-    ///     metadata: None,
-    /// }).await?;
+    /// let result = client
+    ///     .messages()
+    ///     .send_batch(SendBatchRequest::new(vec![
+    ///         BatchMessageItem::new("+15551234567", "Hello Alice!"),
+    ///         BatchMessageItem::new("+15559876543", "Hello Bob!"),
+    ///     ]))
+    ///     .await?;
     ///
     /// println!("Batch {}: {} queued", result.batch_id, result.queued);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn send_batch(&self, request: SendBatchRequest) -> Result<BatchMessageResponse> {
+    ///
+    /// For personalized batches, send a template ID and per-item variables
+    /// instead of rendering every text yourself:
+    ///
+    /// ```rust,no_run
+    /// use std::collections::HashMap;
+    /// use sendly::{BatchMessageItem, SendBatchRequest, Sendly};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let vars = |name: &str| HashMap::from([("name".to_string(), name.to_string())]);
+    /// let result = client
+    ///     .messages()
+    ///     .send_batch(SendBatchRequest::templated(
+    ///         "tpl_welcome",
+    ///         vec![
+    ///             BatchMessageItem::templated("+15551234567", vars("Alice")),
+    ///             BatchMessageItem::templated("+15559876543", vars("Bob")),
+    ///         ],
+    ///     ))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_batch(&self, mut request: SendBatchRequest) -> Result<BatchMessageResponse> {
         if request.messages.is_empty() {
             return Err(Error::Validation {
                 message: "Messages array is required".to_string(),
             });
         }
 
-        // Validate each message
-        for (i, msg) in request.messages.iter().enumerate() {
-            validate_phone(&msg.to).map_err(|_| Error::Validation {
-                message: format!("Invalid phone number at index {}", i),
-            })?;
-            validate_text(&msg.text).map_err(|_| Error::Validation {
-                message: format!("Invalid message text at index {}", i),
-            })?;
-        }
+        validate_batch_items(&request)?;
+        self.validate_batch_metadata(&request)?;
+
+        request.from = self.resolve_from(request.from);
 
         let response = self.client.post("/messages/batch", &request).await?;
         let result: BatchMessageResponse = response.json().await?;
@@ -510,6 +1332,17 @@ impl<'a> Messages<'a> {
         Ok(result)
     }
 
+    /// Checks whether a batch exists.
+    ///
+    /// Returns `Ok(false)` on a 404 and propagates any other error.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_id` - Batch ID
+    pub async fn batch_exists(&self, batch_id: &str) -> Result<bool> {
+        found(self.get_batch(batch_id).await)
+    }
+
     /// Lists batches.
     ///
     /// # Arguments
@@ -554,23 +1387,13 @@ impl<'a> Messages<'a> {
     /// # async fn example() -> sendly::Result<()> {
     /// let client = Sendly::new("sk_live_v1_xxx");
     ///
-    /// let preview = client.messages().preview_batch(SendBatchRequest {
-    ///     messages: vec![
-    ///         BatchMessageItem {
-    ///             to: "+15551234567".to_string(),
-    ///             text: "Hello Alice!".to_string(),
-    ///             metadata: None,
-    ///         },
-    ///         BatchMessageItem {
-    ///             to: "+15559876543".to_string(),
-    ///             text: "Hello Bob!".to_string(),
-    ///             metadata: None,
-    ///         },
-    ///     ],
-    ///     from: None,
-    ///     message_type: None,
-    ///     metadata: None,
-    /// }).await?;
+    /// let preview = client
+    ///     .messages()
+    ///     .preview_batch(SendBatchRequest::new(vec![
+    ///         BatchMessageItem::new("+15551234567", "Hello Alice!"),
+    ///         BatchMessageItem::new("+15559876543", "Hello Bob!"),
+    ///     ]))
+    ///     .await?;
     ///
     /// println!("Can send: {}", preview.can_send);
     /// println!("Credits needed: {}", preview.credits_needed);
@@ -584,15 +1407,8 @@ impl<'a> Messages<'a> {
             });
         }
 
-        // Validate each message
-        for (i, msg) in request.messages.iter().enumerate() {
-            validate_phone(&msg.to).map_err(|_| Error::Validation {
-                message: format!("Invalid phone number at index {}", i),
-            })?;
-            validate_text(&msg.text).map_err(|_| Error::Validation {
-                message: format!("Invalid message text at index {}", i),
-            })?;
-        }
+        validate_batch_items(&request)?;
+        self.validate_batch_metadata(&request)?;
 
         let response = self
             .client
@@ -602,4 +1418,138 @@ impl<'a> Messages<'a> {
 
         Ok(result)
     }
+
+    // ==================== Inbound Methods ====================
+
+    /// Lists messages received by your numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{ListInboundMessagesOptions, Sendly};
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let replies = client.messages().list_inbound(Some(
+    ///     ListInboundMessagesOptions::new()
+    ///         .from("+15551234567")
+    ///         .received_after("2025-01-01T00:00:00Z")
+    /// )).await?;
+    ///
+    /// for reply in replies {
+    ///     println!("{}: {}", reply.from, reply.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_inbound(
+        &self,
+        options: Option<ListInboundMessagesOptions>,
+    ) -> Result<InboundMessageList> {
+        let query = options.map(|o| o.to_query_params()).unwrap_or_default();
+
+        let response = self.client.get("/messages/inbound", &query).await?;
+        let result: InboundMessageList = response.json().await?;
+
+        Ok(result)
+    }
+
+    /// Iterates over all inbound messages with automatic pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional query options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    /// use futures::StreamExt;
+    /// use tokio::pin;
+    ///
+    /// # async fn example() -> sendly::Result<()> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    /// let messages = client.messages();
+    /// let stream = messages.iter_inbound(None);
+    /// pin!(stream);
+    /// while let Some(result) = stream.next().await {
+    ///     let reply = result?;
+    ///     if reply.text.trim().eq_ignore_ascii_case("STOP") {
+    ///         println!("Opt-out from {}", reply.from);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_inbound(
+        &self,
+        options: Option<ListInboundMessagesOptions>,
+    ) -> impl futures::Stream<Item = Result<InboundMessage>> + '_ {
+        let options = options.unwrap_or_default();
+        let mut offset = options.offset.unwrap_or(0);
+        let batch_size = options.limit.filter(|&l| l > 0).unwrap_or(100);
+
+        async_stream::try_stream! {
+            loop {
+                let list_opts = options.clone().limit(batch_size).offset(offset);
+                let page = self.list_inbound(Some(list_opts)).await?;
+
+                let page_len = page.len();
+                let total = page.total();
+
+                for message in page {
+                    yield message;
+                }
+
+                if page_len < batch_size as usize {
+                    break;
+                }
+                if total > 0 && offset as usize + page_len >= total as usize {
+                    break;
+                }
+
+                offset += batch_size;
+            }
+        }
+    }
+
+    /// Falls back to the configured default sender when `from` is unset.
+    fn resolve_from(&self, from: Option<String>) -> Option<String> {
+        from.or_else(|| self.client.config().default_from.clone())
+    }
+
+    /// Checks metadata against the configured limits.
+    fn validate_metadata(
+        &self,
+        metadata: Option<&std::collections::HashMap<String, serde_json::Value>>,
+    ) -> Result<()> {
+        match metadata {
+            Some(metadata) => {
+                validate_metadata_with_limits(metadata, &self.client.config().metadata_limits)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Checks batch-level and per-item metadata against the configured limits.
+    fn validate_batch_metadata(&self, request: &SendBatchRequest) -> Result<()> {
+        self.validate_metadata(request.metadata.as_ref())?;
+
+        for (i, msg) in request.messages.iter().enumerate() {
+            self.validate_metadata(msg.metadata.as_ref())
+                .map_err(|e| match e {
+                    Error::Validation { message } => Error::Validation {
+                        message: format!("{} at index {}", message, i),
+                    },
+                    e => e,
+                })?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::verify::Channel;
+
 /// Message delivery status.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -14,36 +16,78 @@ pub enum MessageStatus {
     Failed,
     /// Message bounced (carrier rejected).
     Bounced,
+    /// Message was cancelled before reaching the carrier.
+    Cancelled,
+}
+
+impl MessageStatus {
+    /// Returns the API string for this status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageStatus::Queued => "queued",
+            MessageStatus::Sent => "sent",
+            MessageStatus::Delivered => "delivered",
+            MessageStatus::Failed => "failed",
+            MessageStatus::Bounced => "bounced",
+            MessageStatus::Cancelled => "cancelled",
+        }
+    }
 }
 
 impl std::fmt::Display for MessageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for MessageStatus {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "queued" => Ok(MessageStatus::Queued),
+            "sent" => Ok(MessageStatus::Sent),
+            "delivered" => Ok(MessageStatus::Delivered),
+            "failed" => Ok(MessageStatus::Failed),
+            "bounced" => Ok(MessageStatus::Bounced),
+            "cancelled" => Ok(MessageStatus::Cancelled),
+            _ => Err(crate::error::Error::Validation {
+                message: format!("Unknown message status: {}", s),
+            }),
+        }
+    }
+}
+
+/// Sort order for list results (by creation time).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// Oldest first.
+    Asc,
+    /// Newest first.
+    Desc,
+}
+
+impl std::fmt::Display for SortOrder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            MessageStatus::Queued => write!(f, "queued"),
-            MessageStatus::Sent => write!(f, "sent"),
-            MessageStatus::Delivered => write!(f, "delivered"),
-            MessageStatus::Failed => write!(f, "failed"),
-            MessageStatus::Bounced => write!(f, "bounced"),
+            SortOrder::Asc => write!(f, "asc"),
+            SortOrder::Desc => write!(f, "desc"),
         }
     }
 }
 
 /// Message direction.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum MessageDirection {
     /// Outbound message (sent by you).
+    #[default]
     Outbound,
     /// Inbound message (received from recipient).
     Inbound,
 }
 
-impl Default for MessageDirection {
-    fn default() -> Self {
-        MessageDirection::Outbound
-    }
-}
-
 /// Sender type.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -78,6 +122,9 @@ pub struct Message {
     /// Number of SMS segments.
     #[serde(default = "default_segments")]
     pub segments: i32,
+    /// Text encoding (e.g. "GSM-7" or "UCS-2"), if reported.
+    #[serde(default)]
+    pub encoding: Option<String>,
     /// Credits consumed.
     #[serde(default, alias = "creditsUsed")]
     pub credits_used: i32,
@@ -138,6 +185,105 @@ impl Message {
     pub fn is_pending(&self) -> bool {
         matches!(self.status, MessageStatus::Queued | MessageStatus::Sent)
     }
+
+    /// Returns true if the message was sent with a live key (not sandbox).
+    pub fn is_live(&self) -> bool {
+        !self.is_sandbox
+    }
+
+    /// Returns true if the message was encoded as UCS-2.
+    ///
+    /// A single character outside GSM-7 (e.g. an emoji) switches the whole
+    /// message to UCS-2, cutting segment size from 160 to 70 characters.
+    /// Returns false when the API did not report an encoding.
+    pub fn is_unicode(&self) -> bool {
+        self.encoding.as_deref().is_some_and(|encoding| {
+            let normalized: String = encoding
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .collect::<String>()
+                .to_ascii_lowercase();
+            matches!(normalized.as_str(), "ucs2" | "utf16" | "unicode")
+        })
+    }
+
+    /// Returns the structured delivery error, if the message carries one.
+    pub fn delivery_error(&self) -> Option<MessageError> {
+        if self.error_code.is_none() && self.error_message.is_none() && self.error.is_none() {
+            return None;
+        }
+
+        Some(MessageError {
+            code: self.error_code.clone(),
+            message: self.error_message.clone().or_else(|| self.error.clone()),
+        })
+    }
+}
+
+/// Broad category of a delivery error, for routing on failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageErrorCategory {
+    /// The number cannot receive messages (unreachable, invalid, landline).
+    Undeliverable,
+    /// Delivery was blocked, e.g. the recipient opted out.
+    Blocked,
+    /// The carrier rejected or filtered the message.
+    CarrierRejected,
+    /// Any code not recognised by this version of the SDK.
+    Unknown,
+}
+
+impl MessageErrorCategory {
+    /// Maps a carrier or Sendly error code to its category.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "30003" | "30005" | "30006" | "invalid_number" | "unroutable_destination" => {
+                MessageErrorCategory::Undeliverable
+            }
+            "30004" | "21610" => MessageErrorCategory::Blocked,
+            "30007" | "carrier_violation" => MessageErrorCategory::CarrierRejected,
+            _ => MessageErrorCategory::Unknown,
+        }
+    }
+}
+
+/// Delivery error details of a failed message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageError {
+    /// Carrier or Sendly error code.
+    pub code: Option<String>,
+    /// Human-readable error message.
+    pub message: Option<String>,
+}
+
+impl MessageError {
+    /// Returns the error category derived from the code.
+    pub fn category(&self) -> MessageErrorCategory {
+        self.code
+            .as_deref()
+            .map(MessageErrorCategory::from_code)
+            .unwrap_or(MessageErrorCategory::Unknown)
+    }
+}
+
+/// One entry in a message's delivery timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageEvent {
+    /// Status the message moved to (e.g. "queued", "sent", "delivered").
+    pub status: String,
+    /// When the status change happened.
+    #[serde(alias = "createdAt", alias = "created_at")]
+    pub timestamp: String,
+    /// Extra detail reported with the change, such as a carrier response.
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+impl MessageEvent {
+    /// Returns the status as a [`MessageStatus`], if it is one.
+    pub fn message_status(&self) -> Option<MessageStatus> {
+        self.status.parse().ok()
+    }
 }
 
 /// Message type for compliance handling.
@@ -159,19 +305,93 @@ impl std::fmt::Display for MessageType {
     }
 }
 
-/// Request to send an SMS message.
+/// Request to send a message.
+///
+/// Build one with [`SendMessageRequest::new`] rather than a struct literal so
+/// that new optional fields don't break call sites. The fields are `to` and
+/// `text` (required) plus optional `from`, `message_type`, `metadata`,
+/// `channel` and `fallback_channel`.
 #[derive(Debug, Clone, Serialize)]
 pub struct SendMessageRequest {
     /// Recipient phone number in E.164 format.
     pub to: String,
-    /// Message content (max 1600 characters).
+    /// Message content (max 1600 characters for SMS).
     pub text: String,
+    /// Sender ID or phone number (optional, falls back to the configured default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
     /// Message type: "marketing" (default, subject to quiet hours) or "transactional" (24/7).
     #[serde(skip_serializing_if = "Option::is_none", rename = "messageType")]
     pub message_type: Option<MessageType>,
     /// Custom metadata to attach to the message (max 4KB).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// Channel to send over (SMS if unset).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Channel>,
+    /// Channel to fail over to if delivery on `channel` fails.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fallbackChannel")]
+    pub fallback_channel: Option<Channel>,
+}
+
+impl SendMessageRequest {
+    /// Creates a new send request.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Recipient phone number in E.164 format
+    /// * `text` - Message content
+    pub fn new(to: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            to: to.into(),
+            text: text.into(),
+            from: None,
+            message_type: None,
+            metadata: None,
+            channel: None,
+            fallback_channel: None,
+        }
+    }
+
+    /// Sets the sender ID or phone number.
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Sets the message type.
+    pub fn message_type(mut self, message_type: MessageType) -> Self {
+        self.message_type = Some(message_type);
+        self
+    }
+
+    /// Sets custom metadata.
+    pub fn metadata(
+        mut self,
+        metadata: std::collections::HashMap<String, serde_json::Value>,
+    ) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the channel to send over.
+    pub fn channel(mut self, channel: Channel) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the channel to fail over to if delivery fails.
+    pub fn fallback_channel(mut self, channel: Channel) -> Self {
+        self.fallback_channel = Some(channel);
+        self
+    }
+
+    /// Returns true if the message may go out over SMS, either on its
+    /// channel or by failing over to it.
+    pub(crate) fn may_use_sms(&self) -> bool {
+        self.channel.as_ref().is_none_or(|c| *c == Channel::Sms)
+            || self.fallback_channel == Some(Channel::Sms)
+    }
 }
 
 /// Options for listing messages.
@@ -185,6 +405,14 @@ pub struct ListMessagesOptions {
     pub status: Option<MessageStatus>,
     /// Filter by recipient phone number.
     pub to: Option<String>,
+    /// Only messages created after this time (RFC 3339).
+    pub created_after: Option<String>,
+    /// Only messages created before this time (RFC 3339).
+    pub created_before: Option<String>,
+    /// Sort order by creation time (server default if unset).
+    pub sort: Option<SortOrder>,
+    /// Only sandbox (`true`) or only live (`false`) messages.
+    pub sandbox: Option<bool>,
 }
 
 impl ListMessagesOptions {
@@ -194,6 +422,8 @@ impl ListMessagesOptions {
     }
 
     /// Sets the limit.
+    ///
+    /// Values above 100 are clamped; `iter` treats `0` as the default page size of 100.
     pub fn limit(mut self, limit: u32) -> Self {
         self.limit = Some(limit.min(100));
         self
@@ -217,6 +447,42 @@ impl ListMessagesOptions {
         self
     }
 
+    /// Only returns messages created after this time (RFC 3339).
+    pub fn created_after(mut self, timestamp: impl Into<String>) -> Self {
+        self.created_after = Some(timestamp.into());
+        self
+    }
+
+    /// Only returns messages created before this time (RFC 3339).
+    pub fn created_before(mut self, timestamp: impl Into<String>) -> Self {
+        self.created_before = Some(timestamp.into());
+        self
+    }
+
+    /// Only returns messages created after this time.
+    #[cfg(feature = "chrono")]
+    pub fn created_after_datetime(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created_after(time.to_rfc3339())
+    }
+
+    /// Only returns messages created before this time.
+    #[cfg(feature = "chrono")]
+    pub fn created_before_datetime(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created_before(time.to_rfc3339())
+    }
+
+    /// Sets the sort order by creation time.
+    pub fn sort(mut self, order: SortOrder) -> Self {
+        self.sort = Some(order);
+        self
+    }
+
+    /// Only returns sandbox messages (`true`) or live messages (`false`).
+    pub fn sandbox(mut self, sandbox: bool) -> Self {
+        self.sandbox = Some(sandbox);
+        self
+    }
+
     pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
         let mut params = Vec::new();
 
@@ -227,24 +493,76 @@ impl ListMessagesOptions {
             params.push(("offset".to_string(), offset.to_string()));
         }
         if let Some(ref status) = self.status {
-            params.push(("status".to_string(), status.to_string()));
+            params.push(("status".to_string(), status.as_str().to_string()));
         }
         if let Some(ref to) = self.to {
             params.push(("to".to_string(), to.clone()));
         }
+        if let Some(ref created_after) = self.created_after {
+            params.push(("createdAfter".to_string(), created_after.clone()));
+        }
+        if let Some(ref created_before) = self.created_before {
+            params.push(("createdBefore".to_string(), created_before.clone()));
+        }
+        if let Some(sort) = self.sort {
+            params.push(("sort".to_string(), format!("createdAt:{}", sort)));
+        }
+        if let Some(sandbox) = self.sandbox {
+            params.push(("isSandbox".to_string(), sandbox.to_string()));
+        }
 
         params
     }
 }
 
 /// Paginated list of messages.
+///
+/// Accepts both `{data, count}` and `{messages, total}` response shapes.
 #[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "RawMessageList")]
 pub struct MessageList {
     /// Messages in this page.
     pub data: Vec<Message>,
     /// Total count of messages matching the query.
-    #[serde(default)]
     pub count: i32,
+    /// Page size echoed by the server.
+    pub limit: Option<i32>,
+    /// Offset of this page, echoed by the server.
+    pub offset: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct RawMessageList {
+    #[serde(default)]
+    data: Option<Vec<Message>>,
+    #[serde(default)]
+    messages: Option<Vec<Message>>,
+    #[serde(default)]
+    count: Option<i32>,
+    #[serde(default)]
+    total: Option<i32>,
+    #[serde(default)]
+    limit: Option<i32>,
+    #[serde(default)]
+    offset: Option<i32>,
+}
+
+impl TryFrom<RawMessageList> for MessageList {
+    type Error = String;
+
+    fn try_from(raw: RawMessageList) -> std::result::Result<Self, Self::Error> {
+        let data = raw
+            .data
+            .or(raw.messages)
+            .ok_or_else(|| "missing field `data`".to_string())?;
+
+        Ok(Self {
+            data,
+            count: raw.count.or(raw.total).unwrap_or_default(),
+            limit: raw.limit,
+            offset: raw.offset,
+        })
+    }
 }
 
 impl MessageList {
@@ -263,6 +581,22 @@ impl MessageList {
         self.count
     }
 
+    /// Returns true if more messages follow this page.
+    pub fn has_more(&self) -> bool {
+        has_more(self.data.len(), self.count, self.limit, self.offset)
+    }
+
+    /// Returns the offset of the next page, if there is one.
+    pub fn next_offset(&self) -> Option<u32> {
+        self.has_more()
+            .then(|| self.offset.unwrap_or(0).max(0) as u32 + self.data.len() as u32)
+    }
+
+    /// Returns the credits used by the messages in this page.
+    pub fn total_credits(&self) -> i64 {
+        self.data.iter().map(|m| i64::from(m.credits_used)).sum()
+    }
+
     /// Returns the first message.
     pub fn first(&self) -> Option<&Message> {
         self.data.first()
@@ -288,6 +622,198 @@ impl IntoIterator for MessageList {
     }
 }
 
+impl<'a> IntoIterator for &'a MessageList {
+    type Item = &'a Message;
+    type IntoIter = std::slice::Iter<'a, Message>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+/// Uses the total count when the server reports one, falling back to
+/// whether the page came back full.
+fn has_more(len: usize, count: i32, limit: Option<i32>, offset: Option<i32>) -> bool {
+    if count > 0 {
+        return offset.unwrap_or(0).max(0) as usize + len < count as usize;
+    }
+    limit.is_some_and(|limit| limit > 0 && len >= limit as usize)
+}
+
+/// Result of previewing a single message (dry run).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessagePreview {
+    /// Whether the message can be sent with the current balance.
+    #[serde(default, alias = "canSend")]
+    pub can_send: bool,
+    /// Number of SMS segments.
+    #[serde(default = "default_segments")]
+    pub segments: i32,
+    /// Credits needed to send.
+    #[serde(default, alias = "creditsNeeded")]
+    pub credits_needed: i32,
+    /// Text encoding (e.g. "GSM-7" or "UCS-2").
+    #[serde(default)]
+    pub encoding: Option<String>,
+    /// Current credit balance.
+    #[serde(default, alias = "currentBalance")]
+    pub current_balance: Option<i32>,
+    /// Reason the message cannot be sent, if any.
+    #[serde(default, alias = "blockReason")]
+    pub block_reason: Option<String>,
+}
+
+// ==================== Inbound Messages ====================
+
+/// An SMS message received by one of your numbers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboundMessage {
+    /// Unique message identifier.
+    pub id: String,
+    /// Sender phone number in E.164 format.
+    pub from: String,
+    /// Your number that received the message.
+    pub to: String,
+    /// Message content.
+    pub text: String,
+    /// Receipt timestamp.
+    #[serde(default, alias = "receivedAt")]
+    pub received_at: Option<String>,
+}
+
+/// Options for listing inbound messages.
+#[derive(Debug, Clone, Default)]
+pub struct ListInboundMessagesOptions {
+    /// Maximum messages to return (default: 20, max: 100).
+    pub limit: Option<u32>,
+    /// Number of messages to skip.
+    pub offset: Option<u32>,
+    /// Filter by sender phone number.
+    pub from: Option<String>,
+    /// Only messages received after this time (RFC 3339).
+    pub received_after: Option<String>,
+    /// Only messages received before this time (RFC 3339).
+    pub received_before: Option<String>,
+}
+
+impl ListInboundMessagesOptions {
+    /// Creates new default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the limit.
+    ///
+    /// Values above 100 are clamped; `iter_inbound` treats `0` as the default page size of 100.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit.min(100));
+        self
+    }
+
+    /// Sets the offset.
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the sender filter.
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Only returns messages received after this time (RFC 3339).
+    pub fn received_after(mut self, timestamp: impl Into<String>) -> Self {
+        self.received_after = Some(timestamp.into());
+        self
+    }
+
+    /// Only returns messages received before this time (RFC 3339).
+    pub fn received_before(mut self, timestamp: impl Into<String>) -> Self {
+        self.received_before = Some(timestamp.into());
+        self
+    }
+
+    /// Only returns messages received after this time.
+    #[cfg(feature = "chrono")]
+    pub fn received_after_datetime(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.received_after(time.to_rfc3339())
+    }
+
+    /// Only returns messages received before this time.
+    #[cfg(feature = "chrono")]
+    pub fn received_before_datetime(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.received_before(time.to_rfc3339())
+    }
+
+    pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+
+        if let Some(limit) = self.limit {
+            params.push(("limit".to_string(), limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(ref from) = self.from {
+            params.push(("from".to_string(), from.clone()));
+        }
+        if let Some(ref received_after) = self.received_after {
+            params.push(("receivedAfter".to_string(), received_after.clone()));
+        }
+        if let Some(ref received_before) = self.received_before {
+            params.push(("receivedBefore".to_string(), received_before.clone()));
+        }
+
+        params
+    }
+}
+
+/// Paginated list of inbound messages.
+#[derive(Debug, Clone, Deserialize)]
+pub struct InboundMessageList {
+    /// Inbound messages in this page.
+    pub data: Vec<InboundMessage>,
+    /// Total count of inbound messages matching the query.
+    #[serde(default)]
+    pub count: i32,
+}
+
+impl InboundMessageList {
+    /// Returns the number of inbound messages in this page.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the total count.
+    pub fn total(&self) -> i32 {
+        self.count
+    }
+}
+
+impl IntoIterator for InboundMessageList {
+    type Item = InboundMessage;
+    type IntoIter = std::vec::IntoIter<InboundMessage>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a InboundMessageList {
+    type Item = &'a InboundMessage;
+    type IntoIter = std::slice::Iter<'a, InboundMessage>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
 // ==================== Scheduled Messages ====================
 
 /// Status of a scheduled message.
@@ -304,13 +830,36 @@ pub enum ScheduledMessageStatus {
     Failed,
 }
 
+impl ScheduledMessageStatus {
+    /// Returns the API string for this status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScheduledMessageStatus::Scheduled => "scheduled",
+            ScheduledMessageStatus::Sent => "sent",
+            ScheduledMessageStatus::Cancelled => "cancelled",
+            ScheduledMessageStatus::Failed => "failed",
+        }
+    }
+}
+
 impl std::fmt::Display for ScheduledMessageStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ScheduledMessageStatus::Scheduled => write!(f, "scheduled"),
-            ScheduledMessageStatus::Sent => write!(f, "sent"),
-            ScheduledMessageStatus::Cancelled => write!(f, "cancelled"),
-            ScheduledMessageStatus::Failed => write!(f, "failed"),
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ScheduledMessageStatus {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "scheduled" => Ok(ScheduledMessageStatus::Scheduled),
+            "sent" => Ok(ScheduledMessageStatus::Sent),
+            "cancelled" => Ok(ScheduledMessageStatus::Cancelled),
+            "failed" => Ok(ScheduledMessageStatus::Failed),
+            _ => Err(crate::error::Error::Validation {
+                message: format!("Unknown scheduled message status: {}", s),
+            }),
         }
     }
 }
@@ -387,6 +936,87 @@ pub struct ScheduleMessageRequest {
     pub metadata: Option<std::collections::HashMap<String, serde_json::Value>>,
 }
 
+impl ScheduleMessageRequest {
+    /// Creates a new schedule request.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Recipient phone number in E.164 format
+    /// * `text` - Message content
+    /// * `scheduled_at` - When to send the message (ISO 8601)
+    pub fn new(
+        to: impl Into<String>,
+        text: impl Into<String>,
+        scheduled_at: impl Into<String>,
+    ) -> Self {
+        Self {
+            to: to.into(),
+            text: text.into(),
+            scheduled_at: scheduled_at.into(),
+            from: None,
+            message_type: None,
+            metadata: None,
+        }
+    }
+
+    /// Sets the sender ID or phone number.
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Sets the message type.
+    pub fn message_type(mut self, message_type: MessageType) -> Self {
+        self.message_type = Some(message_type);
+        self
+    }
+
+    /// Sets custom metadata.
+    pub fn metadata(
+        mut self,
+        metadata: std::collections::HashMap<String, serde_json::Value>,
+    ) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+}
+
+/// Changes to a scheduled message that has not been sent yet.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateScheduledRequest {
+    /// New message content (max 1600 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// New send time (ISO 8601).
+    #[serde(skip_serializing_if = "Option::is_none", rename = "scheduledAt")]
+    pub scheduled_at: Option<String>,
+}
+
+impl UpdateScheduledRequest {
+    /// Creates an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the message text.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Moves the send time (ISO 8601).
+    pub fn scheduled_at(mut self, scheduled_at: impl Into<String>) -> Self {
+        self.scheduled_at = Some(scheduled_at.into());
+        self
+    }
+
+    /// Moves the send time.
+    #[cfg(feature = "chrono")]
+    pub fn scheduled_at_datetime(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.scheduled_at(time.to_rfc3339())
+    }
+}
+
 /// Options for listing scheduled messages.
 #[derive(Debug, Clone, Default)]
 pub struct ListScheduledMessagesOptions {
@@ -396,6 +1026,14 @@ pub struct ListScheduledMessagesOptions {
     pub offset: Option<u32>,
     /// Filter by status.
     pub status: Option<ScheduledMessageStatus>,
+    /// Filter by recipient phone number (E.164).
+    pub to: Option<String>,
+    /// Only messages scheduled at or after this time (RFC 3339).
+    pub scheduled_after: Option<String>,
+    /// Only messages scheduled before this time (RFC 3339).
+    pub scheduled_before: Option<String>,
+    /// Sort order by creation time (server default if unset).
+    pub sort: Option<SortOrder>,
 }
 
 impl ListScheduledMessagesOptions {
@@ -422,6 +1060,42 @@ impl ListScheduledMessagesOptions {
         self
     }
 
+    /// Sets the recipient filter.
+    pub fn to(mut self, to: impl Into<String>) -> Self {
+        self.to = Some(to.into());
+        self
+    }
+
+    /// Only returns messages scheduled at or after this time (RFC 3339).
+    pub fn scheduled_after(mut self, timestamp: impl Into<String>) -> Self {
+        self.scheduled_after = Some(timestamp.into());
+        self
+    }
+
+    /// Only returns messages scheduled before this time (RFC 3339).
+    pub fn scheduled_before(mut self, timestamp: impl Into<String>) -> Self {
+        self.scheduled_before = Some(timestamp.into());
+        self
+    }
+
+    /// Only returns messages scheduled at or after this time.
+    #[cfg(feature = "chrono")]
+    pub fn scheduled_after_datetime(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.scheduled_after(time.to_rfc3339())
+    }
+
+    /// Only returns messages scheduled before this time.
+    #[cfg(feature = "chrono")]
+    pub fn scheduled_before_datetime(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.scheduled_before(time.to_rfc3339())
+    }
+
+    /// Sets the sort order by creation time.
+    pub fn sort(mut self, order: SortOrder) -> Self {
+        self.sort = Some(order);
+        self
+    }
+
     pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
         let mut params = Vec::new();
 
@@ -432,7 +1106,19 @@ impl ListScheduledMessagesOptions {
             params.push(("offset".to_string(), offset.to_string()));
         }
         if let Some(ref status) = self.status {
-            params.push(("status".to_string(), status.to_string()));
+            params.push(("status".to_string(), status.as_str().to_string()));
+        }
+        if let Some(ref to) = self.to {
+            params.push(("to".to_string(), to.clone()));
+        }
+        if let Some(ref scheduled_after) = self.scheduled_after {
+            params.push(("scheduledAfter".to_string(), scheduled_after.clone()));
+        }
+        if let Some(ref scheduled_before) = self.scheduled_before {
+            params.push(("scheduledBefore".to_string(), scheduled_before.clone()));
+        }
+        if let Some(sort) = self.sort {
+            params.push(("sort".to_string(), format!("createdAt:{}", sort)));
         }
 
         params
@@ -447,6 +1133,12 @@ pub struct ScheduledMessageList {
     /// Total count of scheduled messages.
     #[serde(default)]
     pub count: i32,
+    /// Page size echoed by the server.
+    #[serde(default)]
+    pub limit: Option<i32>,
+    /// Offset of this page, echoed by the server.
+    #[serde(default)]
+    pub offset: Option<i32>,
 }
 
 impl ScheduledMessageList {
@@ -464,6 +1156,17 @@ impl ScheduledMessageList {
     pub fn total(&self) -> i32 {
         self.count
     }
+
+    /// Returns true if more scheduled messages follow this page.
+    pub fn has_more(&self) -> bool {
+        has_more(self.data.len(), self.count, self.limit, self.offset)
+    }
+
+    /// Returns the offset of the next page, if there is one.
+    pub fn next_offset(&self) -> Option<u32> {
+        self.has_more()
+            .then(|| self.offset.unwrap_or(0).max(0) as u32 + self.data.len() as u32)
+    }
 }
 
 impl IntoIterator for ScheduledMessageList {
@@ -475,6 +1178,15 @@ impl IntoIterator for ScheduledMessageList {
     }
 }
 
+impl<'a> IntoIterator for &'a ScheduledMessageList {
+    type Item = &'a ScheduledMessage;
+    type IntoIter = std::slice::Iter<'a, ScheduledMessage>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
 /// Response from cancelling a scheduled message.
 #[derive(Debug, Clone, Deserialize)]
 pub struct CancelScheduledMessageResponse {
@@ -503,29 +1215,104 @@ pub enum BatchStatus {
     Failed,
 }
 
+impl BatchStatus {
+    /// Returns the API string for this status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchStatus::Processing => "processing",
+            BatchStatus::Completed => "completed",
+            BatchStatus::PartialFailure => "partial_failure",
+            BatchStatus::Failed => "failed",
+        }
+    }
+}
+
 impl std::fmt::Display for BatchStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BatchStatus::Processing => write!(f, "processing"),
-            BatchStatus::Completed => write!(f, "completed"),
-            BatchStatus::PartialFailure => write!(f, "partial_failure"),
-            BatchStatus::Failed => write!(f, "failed"),
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for BatchStatus {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "processing" => Ok(BatchStatus::Processing),
+            "completed" => Ok(BatchStatus::Completed),
+            "partial_failure" => Ok(BatchStatus::PartialFailure),
+            "failed" => Ok(BatchStatus::Failed),
+            _ => Err(crate::error::Error::Validation {
+                message: format!("Unknown batch status: {}", s),
+            }),
         }
     }
 }
 
 /// A single message in a batch request.
+///
+/// Each item carries either its own `text` or, in a templated batch (see
+/// [`SendBatchRequest::template_id`]), the `variables` to render the shared
+/// template with.
 #[derive(Debug, Clone, Serialize)]
 pub struct BatchMessageItem {
     /// Recipient phone number in E.164 format.
     pub to: String,
-    /// Message content (max 1600 characters).
+    /// Message content (max 1600 characters). Empty for templated items.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub text: String,
+    /// Values for the batch template's variables.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<std::collections::HashMap<String, String>>,
     /// Per-message metadata (max 4KB, merged with batch metadata).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<std::collections::HashMap<String, serde_json::Value>>,
 }
 
+impl BatchMessageItem {
+    /// Creates a batch item with its own text.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Recipient phone number in E.164 format
+    /// * `text` - Message content
+    pub fn new(to: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            to: to.into(),
+            text: text.into(),
+            variables: None,
+            metadata: None,
+        }
+    }
+
+    /// Creates a batch item rendered from the batch template.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Recipient phone number in E.164 format
+    /// * `variables` - Values for the template's variables
+    pub fn templated(
+        to: impl Into<String>,
+        variables: std::collections::HashMap<String, String>,
+    ) -> Self {
+        Self {
+            to: to.into(),
+            text: String::new(),
+            variables: Some(variables),
+            metadata: None,
+        }
+    }
+
+    /// Sets per-message metadata.
+    pub fn metadata(
+        mut self,
+        metadata: std::collections::HashMap<String, serde_json::Value>,
+    ) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+}
+
 /// Request to send batch messages.
 #[derive(Debug, Clone, Serialize)]
 pub struct SendBatchRequest {
@@ -540,6 +1327,63 @@ pub struct SendBatchRequest {
     /// Shared metadata for all messages in the batch (max 4KB).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// Template rendered for items that carry `variables` instead of `text`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateId")]
+    pub template_id: Option<String>,
+}
+
+impl SendBatchRequest {
+    /// Creates a batch request.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - Messages to send
+    pub fn new(messages: Vec<BatchMessageItem>) -> Self {
+        Self {
+            messages,
+            from: None,
+            message_type: None,
+            metadata: None,
+            template_id: None,
+        }
+    }
+
+    /// Creates a templated batch, each item supplying its own variables.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_id` - Template to render for every item
+    /// * `messages` - Items built with [`BatchMessageItem::templated`]
+    pub fn templated(template_id: impl Into<String>, messages: Vec<BatchMessageItem>) -> Self {
+        Self::new(messages).template_id(template_id)
+    }
+
+    /// Sets the sender ID or phone number for all messages.
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Sets the message type.
+    pub fn message_type(mut self, message_type: MessageType) -> Self {
+        self.message_type = Some(message_type);
+        self
+    }
+
+    /// Sets shared metadata.
+    pub fn metadata(
+        mut self,
+        metadata: std::collections::HashMap<String, serde_json::Value>,
+    ) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the template rendered for items with `variables`.
+    pub fn template_id(mut self, template_id: impl Into<String>) -> Self {
+        self.template_id = Some(template_id.into());
+        self
+    }
 }
 
 /// Result of a single message in a batch.
@@ -602,6 +1446,16 @@ impl BatchMessageResponse {
     pub fn is_failed(&self) -> bool {
         self.status == BatchStatus::Failed
     }
+
+    /// Returns the fraction of messages sent, from `0.0` to `1.0`.
+    ///
+    /// An empty batch has a rate of `0.0`.
+    pub fn success_rate(&self) -> f64 {
+        if self.total <= 0 {
+            return 0.0;
+        }
+        f64::from(self.sent) / f64::from(self.total)
+    }
 }
 
 /// A single message in a batch preview.
@@ -672,6 +1526,8 @@ pub struct ListBatchesOptions {
     pub offset: Option<u32>,
     /// Filter by status.
     pub status: Option<BatchStatus>,
+    /// Sort order by creation time (server default if unset).
+    pub sort: Option<SortOrder>,
 }
 
 impl ListBatchesOptions {
@@ -698,6 +1554,12 @@ impl ListBatchesOptions {
         self
     }
 
+    /// Sets the sort order by creation time.
+    pub fn sort(mut self, order: SortOrder) -> Self {
+        self.sort = Some(order);
+        self
+    }
+
     pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
         let mut params = Vec::new();
 
@@ -708,7 +1570,10 @@ impl ListBatchesOptions {
             params.push(("offset".to_string(), offset.to_string()));
         }
         if let Some(ref status) = self.status {
-            params.push(("status".to_string(), status.to_string()));
+            params.push(("status".to_string(), status.as_str().to_string()));
+        }
+        if let Some(sort) = self.sort {
+            params.push(("sort".to_string(), format!("createdAt:{}", sort)));
         }
 
         params
@@ -723,6 +1588,12 @@ pub struct BatchList {
     /// Total count of batches.
     #[serde(default)]
     pub count: i32,
+    /// Page size echoed by the server.
+    #[serde(default)]
+    pub limit: Option<i32>,
+    /// Offset of this page, echoed by the server.
+    #[serde(default)]
+    pub offset: Option<i32>,
 }
 
 impl BatchList {
@@ -740,6 +1611,22 @@ impl BatchList {
     pub fn total(&self) -> i32 {
         self.count
     }
+
+    /// Returns true if more batches follow this page.
+    pub fn has_more(&self) -> bool {
+        has_more(self.data.len(), self.count, self.limit, self.offset)
+    }
+
+    /// Returns the offset of the next page, if there is one.
+    pub fn next_offset(&self) -> Option<u32> {
+        self.has_more()
+            .then(|| self.offset.unwrap_or(0).max(0) as u32 + self.data.len() as u32)
+    }
+
+    /// Returns the credits used by the batches in this page.
+    pub fn total_credits(&self) -> i64 {
+        self.data.iter().map(|b| i64::from(b.credits_used)).sum()
+    }
 }
 
 impl IntoIterator for BatchList {
@@ -751,13 +1638,23 @@ impl IntoIterator for BatchList {
     }
 }
 
+impl<'a> IntoIterator for &'a BatchList {
+    type Item = &'a BatchMessageResponse;
+    type IntoIter = std::slice::Iter<'a, BatchMessageResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
 // ==================== Webhook Types ====================
 
 /// Circuit breaker state for webhooks.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CircuitState {
     /// Circuit is closed (healthy).
+    #[default]
     Closed,
     /// Circuit is open (failing).
     Open,
@@ -765,17 +1662,12 @@ pub enum CircuitState {
     HalfOpen,
 }
 
-impl Default for CircuitState {
-    fn default() -> Self {
-        CircuitState::Closed
-    }
-}
-
 /// Webhook mode for event filtering.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum WebhookMode {
     /// Receive both test and live events.
+    #[default]
     All,
     /// Only receive sandbox/test events.
     Test,
@@ -783,9 +1675,20 @@ pub enum WebhookMode {
     Live,
 }
 
-impl Default for WebhookMode {
-    fn default() -> Self {
-        WebhookMode::All
+impl WebhookMode {
+    /// Returns the API string for this mode.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookMode::All => "all",
+            WebhookMode::Test => "test",
+            WebhookMode::Live => "live",
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
@@ -838,6 +1741,27 @@ fn default_true() -> bool {
     true
 }
 
+impl Default for Webhook {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            url: String::new(),
+            events: Vec::new(),
+            mode: WebhookMode::default(),
+            is_active: true,
+            failure_count: 0,
+            circuit_state: CircuitState::default(),
+            api_version: None,
+            total_deliveries: 0,
+            successful_deliveries: 0,
+            success_rate: 0.0,
+            last_delivery_at: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
 impl Webhook {
     /// Returns true if the webhook is healthy (active and circuit closed).
     pub fn is_healthy(&self) -> bool {
@@ -848,6 +1772,60 @@ impl Webhook {
     pub fn is_circuit_open(&self) -> bool {
         self.circuit_state == CircuitState::Open
     }
+
+    /// Returns true if the webhook is healthy and its success rate is at
+    /// least `min_rate` percent.
+    ///
+    /// A webhook with no deliveries yet has nothing to judge, so only the
+    /// circuit and active state are checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_rate` - Minimum success rate, as a percentage (e.g. `95.0`)
+    pub fn is_healthy_with_rate(&self, min_rate: f64) -> bool {
+        self.is_healthy() && (self.total_deliveries == 0 || self.success_rate >= min_rate)
+    }
+}
+
+/// A credential that is redacted when printed.
+///
+/// `Debug` and `Display` print `***`; call [`Secret::expose`] to read the raw value.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Wraps a raw secret value.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the raw secret value.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the wrapper and returns the raw secret value.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Returns true if no secret was returned.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
+impl std::fmt::Display for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***")
+    }
 }
 
 /// Response from creating a webhook (includes secret).
@@ -858,7 +1836,7 @@ pub struct WebhookCreatedResponse {
     pub webhook: Option<Webhook>,
     /// The webhook secret for signature verification.
     #[serde(default)]
-    pub secret: String,
+    pub secret: Secret,
     // Flatten webhook fields for direct responses
     #[serde(flatten)]
     pub data: Option<Webhook>,
@@ -886,6 +1864,35 @@ pub struct CreateWebhookRequest {
     pub api_version: Option<String>,
 }
 
+impl CreateWebhookRequest {
+    /// Creates a request for the given URL and event types.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL to receive webhook events
+    /// * `events` - List of event types to subscribe to
+    pub fn new(url: impl Into<String>, events: Vec<impl Into<String>>) -> Self {
+        Self {
+            url: url.into(),
+            events: events.into_iter().map(|e| e.into()).collect(),
+            mode: None,
+            api_version: None,
+        }
+    }
+
+    /// Sets which events the webhook receives (test, live or all).
+    pub fn mode(mut self, mode: WebhookMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Pins the API version used for webhook payloads.
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
+}
+
 /// Request to update a webhook.
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct UpdateWebhookRequest {
@@ -938,6 +1945,22 @@ fn default_one() -> i32 {
     1
 }
 
+impl Default for WebhookDelivery {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            webhook_id: String::new(),
+            event_type: String::new(),
+            http_status: 0,
+            success: false,
+            attempt_number: 1,
+            error_message: None,
+            response_time_ms: 0,
+            created_at: None,
+        }
+    }
+}
+
 /// List of webhook deliveries.
 #[derive(Debug, Clone, Deserialize)]
 pub struct WebhookDeliveryList {
@@ -974,12 +1997,64 @@ pub struct WebhookTestResult {
 pub struct WebhookSecretRotation {
     /// The new webhook secret.
     #[serde(default)]
-    pub secret: String,
+    pub secret: Secret,
     /// Timestamp of the rotation.
     #[serde(default, alias = "rotatedAt")]
     pub rotated_at: Option<String>,
 }
 
+/// Result of retrying all failed deliveries for a webhook.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetryAllResult {
+    /// Number of deliveries that were retried.
+    pub retried: i32,
+    /// Number of retries that were delivered successfully.
+    pub succeeded: i32,
+    /// Number of retries that failed again or could not be issued.
+    pub failed: i32,
+}
+
+/// Options for listing webhooks.
+#[derive(Debug, Clone, Default)]
+pub struct ListWebhooksOptions {
+    /// Only webhooks subscribed to this event type.
+    pub event: Option<String>,
+    /// Filter by active state.
+    pub active: Option<bool>,
+}
+
+impl ListWebhooksOptions {
+    /// Creates new default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only returns webhooks subscribed to `event` (e.g. `"message.failed"`).
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        self.event = Some(event.into());
+        self
+    }
+
+    /// Sets the active filter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+
+        if let Some(ref event) = self.event {
+            params.push(("event".to_string(), event.clone()));
+        }
+        if let Some(active) = self.active {
+            params.push(("active".to_string(), active.to_string()));
+        }
+
+        params
+    }
+}
+
 /// Options for listing webhook deliveries.
 #[derive(Debug, Clone, Default)]
 pub struct ListDeliveriesOptions {
@@ -987,6 +2062,12 @@ pub struct ListDeliveriesOptions {
     pub limit: Option<u32>,
     /// Number of deliveries to skip.
     pub offset: Option<u32>,
+    /// Filter by delivery outcome.
+    pub success: Option<bool>,
+    /// Only deliveries created at or after this time (ISO 8601).
+    pub from: Option<String>,
+    /// Only deliveries created before this time (ISO 8601).
+    pub to: Option<String>,
 }
 
 impl ListDeliveriesOptions {
@@ -1007,6 +2088,24 @@ impl ListDeliveriesOptions {
         self
     }
 
+    /// Sets the success filter (`false` returns only failed deliveries).
+    pub fn success(mut self, success: bool) -> Self {
+        self.success = Some(success);
+        self
+    }
+
+    /// Sets the start of the date range (ISO 8601, inclusive).
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Sets the end of the date range (ISO 8601, exclusive).
+    pub fn to(mut self, to: impl Into<String>) -> Self {
+        self.to = Some(to.into());
+        self
+    }
+
     pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
         let mut params = Vec::new();
 
@@ -1016,6 +2115,15 @@ impl ListDeliveriesOptions {
         if let Some(offset) = self.offset {
             params.push(("offset".to_string(), offset.to_string()));
         }
+        if let Some(success) = self.success {
+            params.push(("success".to_string(), success.to_string()));
+        }
+        if let Some(ref from) = self.from {
+            params.push(("from".to_string(), from.clone()));
+        }
+        if let Some(ref to) = self.to {
+            params.push(("to".to_string(), to.clone()));
+        }
 
         params
     }
@@ -1039,12 +2147,63 @@ pub struct Credits {
     #[serde(default, alias = "reservedCredits")]
     pub reserved_credits: i32,
     /// Currency code.
-    #[serde(default = "default_currency")]
-    pub currency: String,
+    #[serde(default)]
+    pub currency: Currency,
+}
+
+/// Currency of a credit balance.
+///
+/// Unknown currency codes are kept as [`Currency::Other`] rather than failing
+/// deserialization.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Currency {
+    /// US dollar.
+    #[default]
+    Usd,
+    /// Euro.
+    Eur,
+    /// Any other ISO 4217 code, uppercased.
+    Other(String),
+}
+
+impl Currency {
+    /// Returns the ISO 4217 currency code.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+            Currency::Other(code) => code,
+        }
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for Currency {
+    fn from(code: &str) -> Self {
+        match code.to_ascii_uppercase().as_str() {
+            "USD" => Currency::Usd,
+            "EUR" => Currency::Eur,
+            other => Currency::Other(other.to_string()),
+        }
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-fn default_currency() -> String {
-    "USD".to_string()
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(Currency::from(code.as_str()))
+    }
 }
 
 impl Credits {
@@ -1052,6 +2211,16 @@ impl Credits {
     pub fn has_credits(&self) -> bool {
         self.available_balance > 0
     }
+
+    /// Returns true if the available balance is below `threshold`.
+    pub fn is_low(&self, threshold: i64) -> bool {
+        i64::from(self.available_balance) < threshold
+    }
+
+    /// Returns true if the available balance covers `credits`.
+    pub fn can_afford(&self, credits: i64) -> bool {
+        i64::from(self.available_balance) >= credits
+    }
 }
 
 /// Credit transaction type.
@@ -1068,6 +2237,29 @@ pub enum TransactionType {
     Bonus,
     /// Manual adjustment.
     Adjustment,
+    /// A transaction type this version of the SDK doesn't know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl TransactionType {
+    /// Returns the wire value of the type.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionType::Purchase => "purchase",
+            TransactionType::Usage => "usage",
+            TransactionType::Refund => "refund",
+            TransactionType::Bonus => "bonus",
+            TransactionType::Adjustment => "adjustment",
+            TransactionType::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for TransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// A credit transaction.
@@ -1166,14 +2358,7 @@ impl ListTransactionsOptions {
             params.push(("offset".to_string(), offset.to_string()));
         }
         if let Some(ref t) = self.transaction_type {
-            let type_str = match t {
-                TransactionType::Purchase => "purchase",
-                TransactionType::Usage => "usage",
-                TransactionType::Refund => "refund",
-                TransactionType::Bonus => "bonus",
-                TransactionType::Adjustment => "adjustment",
-            };
-            params.push(("type".to_string(), type_str.to_string()));
+            params.push(("type".to_string(), t.as_str().to_string()));
         }
 
         params
@@ -1213,7 +2398,7 @@ pub struct CreateApiKeyResponse {
     pub api_key: Option<ApiKey>,
     /// The full API key value (only shown once).
     #[serde(default)]
-    pub key: String,
+    pub key: Secret,
 }
 
 /// Request to create an API key.
@@ -1226,6 +2411,36 @@ pub struct CreateApiKeyRequest {
     pub expires_at: Option<String>,
 }
 
+/// Request to update an API key.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UpdateApiKeyRequest {
+    /// New display name for the API key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New expiration date.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "expires_at")]
+    pub expires_at: Option<String>,
+}
+
+impl UpdateApiKeyRequest {
+    /// Creates an empty update request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a new display name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets a new expiration date (ISO 8601).
+    pub fn expires_at(mut self, expires_at: impl Into<String>) -> Self {
+        self.expires_at = Some(expires_at.into());
+        self
+    }
+}
+
 /// Account verification status.
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct AccountVerification {
@@ -1238,6 +2453,15 @@ pub struct AccountVerification {
     /// Whether identity is verified.
     #[serde(default, alias = "identityVerified")]
     pub identity_verified: bool,
+    /// Business verification status (e.g. "pending", "approved").
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Whether the account has passed business verification.
+    #[serde(default)]
+    pub verified: bool,
+    /// When verification was submitted.
+    #[serde(default, alias = "submittedAt")]
+    pub submitted_at: Option<String>,
 }
 
 impl AccountVerification {
@@ -1251,11 +2475,24 @@ impl AccountVerification {
 #[derive(Debug, Clone, Deserialize)]
 pub struct AccountLimits {
     /// Maximum messages per second.
-    #[serde(default = "default_mps", alias = "messagesPerSecond")]
+    #[serde(
+        default = "default_mps",
+        alias = "messagesPerSecond",
+        alias = "rateLimitPerSecond",
+        alias = "rate_limit_per_second"
+    )]
     pub messages_per_second: i32,
     /// Maximum messages per day.
-    #[serde(default = "default_mpd", alias = "messagesPerDay")]
+    #[serde(
+        default = "default_mpd",
+        alias = "messagesPerDay",
+        alias = "dailyMessageLimit",
+        alias = "daily_message_limit"
+    )]
     pub messages_per_day: i32,
+    /// Maximum messages per month, if capped.
+    #[serde(default, alias = "monthlyMessageLimit")]
+    pub monthly_message_limit: Option<i64>,
     /// Maximum batch size.
     #[serde(default = "default_batch", alias = "maxBatchSize")]
     pub max_batch_size: i32,
@@ -1276,6 +2513,7 @@ impl Default for AccountLimits {
         Self {
             messages_per_second: 10,
             messages_per_day: 10000,
+            monthly_message_limit: None,
             max_batch_size: 1000,
         }
     }
@@ -1305,3 +2543,22 @@ pub struct Account {
     #[serde(default, alias = "createdAt")]
     pub created_at: Option<String>,
 }
+
+impl Account {
+    /// Returns true if the account has passed verification.
+    ///
+    /// Accounts that predate business verification report the individual
+    /// email/phone/identity flags instead, so those count too.
+    pub fn is_verified(&self) -> bool {
+        self.verification.verified || self.verification.is_fully_verified()
+    }
+
+    /// Returns true if sending another message stays within the daily limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `sent` - Messages already sent today
+    pub fn within_daily_limit(&self, sent: i64) -> bool {
+        sent < i64::from(self.limits.messages_per_day)
+    }
+}
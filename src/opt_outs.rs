@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::Sendly;
+use crate::error::{Error, Result};
+use crate::validate::validate_phone;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptOut {
+    #[serde(alias = "phoneNumber")]
+    pub phone: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default, alias = "createdAt")]
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OptOutList {
+    pub data: Vec<OptOut>,
+    #[serde(default)]
+    pub count: i32,
+}
+
+impl OptOutList {
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn total(&self) -> i32 {
+        self.count
+    }
+}
+
+impl IntoIterator for OptOutList {
+    type Item = OptOut;
+    type IntoIter = std::vec::IntoIter<OptOut>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ListOptOutsOptions {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+impl ListOptOutsOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit.min(100));
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        if let Some(limit) = self.limit {
+            params.push(("limit".to_string(), limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset".to_string(), offset.to_string()));
+        }
+        params
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AddOptOutRequest<'a> {
+    phone: &'a str,
+}
+
+pub struct OptOutsResource<'a> {
+    client: &'a Sendly,
+}
+
+impl<'a> OptOutsResource<'a> {
+    pub fn new(client: &'a Sendly) -> Self {
+        Self { client }
+    }
+
+    pub async fn list(&self, options: ListOptOutsOptions) -> Result<OptOutList> {
+        let params = options.to_query_params();
+        let response = self.client.get("/opt-outs", &params).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn add(&self, phone: &str) -> Result<OptOut> {
+        validate_phone(phone)?;
+        let response = self
+            .client
+            .post("/opt-outs", &AddOptOutRequest { phone })
+            .await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn remove(&self, phone: &str) -> Result<()> {
+        validate_phone(phone)?;
+        self.client
+            .delete(&format!("/opt-outs/{}", urlencoding::encode(phone)))
+            .await?;
+        Ok(())
+    }
+
+    // A 404 means the number is not on the suppression list
+    pub async fn check(&self, phone: &str) -> Result<bool> {
+        validate_phone(phone)?;
+        match self
+            .client
+            .get(&format!("/opt-outs/{}", urlencoding::encode(phone)), &[])
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(Error::NotFound { .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -1,21 +1,17 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 use crate::client::Sendly;
-use crate::error::Result;
+use crate::error::{Error, Result};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TemplateType {
     Preset,
+    #[default]
     Custom,
 }
 
-impl Default for TemplateType {
-    fn default() -> Self {
-        TemplateType::Custom
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Template {
     pub id: String,
@@ -177,6 +173,26 @@ pub struct TemplatePagination {
     pub has_more: bool,
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct RenderTemplateRequest {
+    variables: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RenderedTemplate {
+    pub text: String,
+    #[serde(default = "default_segments")]
+    pub segments: i32,
+    #[serde(default)]
+    pub encoding: Option<String>,
+    #[serde(default, alias = "characterCount")]
+    pub character_count: Option<i32>,
+}
+
+fn default_segments() -> i32 {
+    1
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct DeleteTemplateResponse {
     pub success: bool,
@@ -184,6 +200,127 @@ pub struct DeleteTemplateResponse {
     pub message: Option<String>,
 }
 
+/// Error from rendering a template body locally.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    /// A `{{name}}` placeholder had no matching variable (strict mode only).
+    #[error("Missing template variable: {0}")]
+    MissingVariable(String),
+    /// A variable was supplied but never used (strict mode only).
+    #[error("Unused template variable: {0}")]
+    UnusedVariable(String),
+    /// A `{{` at this byte offset has no closing `}}`.
+    #[error("Unterminated placeholder at byte {0}")]
+    Unterminated(usize),
+}
+
+impl From<TemplateError> for Error {
+    fn from(err: TemplateError) -> Self {
+        Error::Validation {
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Substitutes `{{name}}` placeholders in `body`, in strict mode.
+///
+/// Equivalent to [`render_template_with`] with `strict` set to `true`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use sendly::render_template;
+///
+/// let vars = HashMap::from([("code".to_string(), "123456".to_string())]);
+/// let text = render_template("Your code is {{ code }}", &vars).unwrap();
+/// assert_eq!(text, "Your code is 123456");
+/// ```
+pub fn render_template(
+    body: &str,
+    vars: &HashMap<String, String>,
+) -> std::result::Result<String, TemplateError> {
+    render_template_with(body, vars, true)
+}
+
+/// Substitutes `{{name}}` placeholders in `body`.
+///
+/// Whitespace inside the braces is ignored. Strict mode rejects missing and
+/// unused variables; lenient mode leaves unknown placeholders as-is and
+/// ignores extra variables. `\{\{` renders as a literal `{{`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use sendly::{render_template_with, TemplateError};
+///
+/// let vars = HashMap::from([("name".to_string(), "Ada".to_string())]);
+///
+/// let text = render_template_with("Hi {{name}}, \\{\\{not a var}} {{other}}", &vars, false);
+/// assert_eq!(text.unwrap(), "Hi Ada, {{not a var}} {{other}}");
+///
+/// let err = render_template_with("Hi {{other}}", &vars, true).unwrap_err();
+/// assert_eq!(err, TemplateError::MissingVariable("other".to_string()));
+/// ```
+pub fn render_template_with(
+    body: &str,
+    vars: &HashMap<String, String>,
+    strict: bool,
+) -> std::result::Result<String, TemplateError> {
+    let mut out = String::with_capacity(body.len());
+    let mut used = HashSet::new();
+    let mut rest = body;
+    let mut pos = 0;
+
+    loop {
+        let escape = rest.find("\\{\\{");
+        let open = rest.find("{{");
+
+        match (escape, open) {
+            (Some(e), o) if o.is_none_or(|o| e < o) => {
+                out.push_str(&rest[..e]);
+                out.push_str("{{");
+                rest = &rest[e + 4..];
+                pos += e + 4;
+            }
+            (_, Some(o)) => {
+                out.push_str(&rest[..o]);
+                let after = &rest[o + 2..];
+                let close = after
+                    .find("}}")
+                    .ok_or(TemplateError::Unterminated(pos + o))?;
+                let name = after[..close].trim();
+                let end = o + 2 + close + 2;
+
+                match vars.get(name) {
+                    Some(value) => {
+                        out.push_str(value);
+                        used.insert(name);
+                    }
+                    None if strict => return Err(TemplateError::MissingVariable(name.to_string())),
+                    None => out.push_str(&rest[o..end]),
+                }
+
+                rest = &rest[end..];
+                pos += end;
+            }
+            _ => {
+                out.push_str(rest);
+                break;
+            }
+        }
+    }
+
+    if strict {
+        if let Some(unused) = vars.keys().filter(|k| !used.contains(k.as_str())).min() {
+            return Err(TemplateError::UnusedVariable(unused.clone()));
+        }
+    }
+
+    Ok(out)
+}
+
 pub struct TemplatesResource<'a> {
     client: &'a Sendly,
 }
@@ -228,6 +365,19 @@ impl<'a> TemplatesResource<'a> {
         Ok(response.json().await?)
     }
 
+    pub async fn render(
+        &self,
+        id: &str,
+        variables: HashMap<String, String>,
+    ) -> Result<RenderedTemplate> {
+        let request = RenderTemplateRequest { variables };
+        let response = self
+            .client
+            .post(&format!("/verify/templates/{}/render", id), &request)
+            .await?;
+        Ok(response.json().await?)
+    }
+
     pub async fn publish(&self, id: &str) -> Result<Template> {
         let response = self
             .client
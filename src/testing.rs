@@ -0,0 +1,126 @@
+//! Fixtures for testing code that uses the Sendly SDK.
+//!
+//! Only available with the `testing` feature, which is meant for
+//! `[dev-dependencies]`:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! sendly = { version = "3", features = ["testing"] }
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use sendly::testing::MockSignature;
+//! use sendly::webhooks::Webhooks;
+//! use sendly::{BatchMessageResponse, Message};
+//!
+//! let message = Message::sample();
+//! assert!(message.is_sandbox);
+//!
+//! let batch = BatchMessageResponse::sample();
+//! assert_eq!(batch.success_rate(), 1.0);
+//!
+//! let signer = MockSignature::new("whsec_test");
+//! let payload = r#"{"id":"evt_1","type":"campaign.completed","data":{},"created_at":"2025-01-15T10:00:00Z"}"#;
+//! let event = Webhooks::parse_event(payload, &signer.sign(payload), signer.secret()).unwrap();
+//! assert_eq!(event.id, "evt_1");
+//! ```
+
+use crate::models::{
+    BatchMessageResponse, BatchMessageResult, BatchStatus, Message, MessageDirection, MessageStatus,
+};
+use crate::webhooks::{Webhooks, SIGNATURE_HEADER};
+
+const SAMPLE_TIMESTAMP: &str = "2025-01-15T10:00:00Z";
+
+impl Message {
+    /// Returns a queued sandbox message to `+15005550000`.
+    ///
+    /// Override fields with struct update syntax, e.g.
+    /// `Message { status: MessageStatus::Failed, ..Message::sample() }`.
+    pub fn sample() -> Self {
+        Self {
+            id: "msg_sample".to_string(),
+            to: "+15005550000".to_string(),
+            from: Some("SENDLY".to_string()),
+            text: "Hello from Sendly!".to_string(),
+            status: MessageStatus::Queued,
+            direction: MessageDirection::Outbound,
+            segments: 1,
+            encoding: Some("GSM-7".to_string()),
+            credits_used: 1,
+            is_sandbox: true,
+            sender_type: None,
+            telnyx_message_id: None,
+            warning: None,
+            sender_note: None,
+            error: None,
+            error_code: None,
+            error_message: None,
+            created_at: Some(SAMPLE_TIMESTAMP.to_string()),
+            updated_at: Some(SAMPLE_TIMESTAMP.to_string()),
+            delivered_at: None,
+            metadata: None,
+        }
+    }
+}
+
+impl BatchMessageResponse {
+    /// Returns a completed batch of two sandbox messages, both sent.
+    pub fn sample() -> Self {
+        let messages: Vec<BatchMessageResult> = ["+15005550000", "+15005550001"]
+            .iter()
+            .enumerate()
+            .map(|(i, to)| BatchMessageResult {
+                to: to.to_string(),
+                message_id: Some(format!("msg_sample_{}", i + 1)),
+                status: "sent".to_string(),
+                error: None,
+            })
+            .collect();
+
+        Self {
+            batch_id: "batch_sample".to_string(),
+            status: BatchStatus::Completed,
+            total: messages.len() as i32,
+            queued: 0,
+            sent: messages.len() as i32,
+            failed: 0,
+            credits_used: messages.len() as i32,
+            messages,
+            created_at: Some(SAMPLE_TIMESTAMP.to_string()),
+            completed_at: Some(SAMPLE_TIMESTAMP.to_string()),
+        }
+    }
+}
+
+/// Signs webhook payloads the way Sendly does, for testing webhook handlers.
+#[derive(Debug, Clone)]
+pub struct MockSignature {
+    secret: String,
+}
+
+impl MockSignature {
+    /// Creates a signer for the given webhook secret.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+        }
+    }
+
+    /// Returns the webhook secret.
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// Returns the signature header value (`sha256=...`) for `payload`.
+    pub fn sign(&self, payload: &str) -> String {
+        Webhooks::generate_signature(payload, &self.secret)
+    }
+
+    /// Returns the header name and value to attach to a test request.
+    pub fn header(&self, payload: &str) -> (&'static str, String) {
+        (SIGNATURE_HEADER, self.sign(payload))
+    }
+}
@@ -0,0 +1,157 @@
+//! Input validation helpers.
+//!
+//! The SDK runs these checks before sending requests; they are public so
+//! callers can validate input (e.g. a phone number typed into a form) up
+//! front with the same rules.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sendly::validate::is_valid_phone;
+//!
+//! assert!(is_valid_phone("+15551234567"));
+//! assert!(!is_valid_phone("555-123-4567"));
+//! ```
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::error::{Error, Result};
+
+static PHONE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn phone_regex() -> &'static Regex {
+    PHONE_REGEX.get_or_init(|| Regex::new(r"^\+[1-9]\d{1,14}$").unwrap())
+}
+
+/// Returns true if `phone` is a valid E.164 number (e.g. `+15551234567`).
+pub fn is_valid_phone(phone: &str) -> bool {
+    phone_regex().is_match(phone)
+}
+
+/// Checks that `phone` is a valid E.164 number.
+///
+/// # Errors
+///
+/// Returns [`Error::Validation`] if it is not.
+pub fn validate_phone(phone: &str) -> Result<()> {
+    if !is_valid_phone(phone) {
+        return Err(Error::Validation {
+            message: "Invalid phone number format. Use E.164 format (e.g., +15551234567)"
+                .to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Default maximum number of metadata keys.
+pub const MAX_METADATA_KEYS: usize = 20;
+
+/// Default maximum length of a metadata key, in characters.
+pub const MAX_METADATA_KEY_LENGTH: usize = 40;
+
+/// Default maximum length of a metadata value, in characters. Non-string
+/// values are measured as serialized JSON.
+pub const MAX_METADATA_VALUE_LENGTH: usize = 500;
+
+/// Default maximum size of a metadata map serialized as JSON, in bytes.
+pub const MAX_METADATA_BYTES: usize = 4096;
+
+/// Size limits applied to metadata maps before they are sent.
+///
+/// Defaults to [`MAX_METADATA_KEYS`], [`MAX_METADATA_KEY_LENGTH`],
+/// [`MAX_METADATA_VALUE_LENGTH`] and [`MAX_METADATA_BYTES`]; set them on the client with
+/// [`SendlyConfig::metadata_limits`](crate::SendlyConfig::metadata_limits).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetadataLimits {
+    /// Maximum number of keys.
+    pub max_keys: usize,
+    /// Maximum key length, in characters.
+    pub max_key_length: usize,
+    /// Maximum value length, in characters.
+    pub max_value_length: usize,
+    /// Maximum size of the whole map serialized as JSON, in bytes.
+    pub max_bytes: usize,
+}
+
+impl Default for MetadataLimits {
+    fn default() -> Self {
+        Self {
+            max_keys: MAX_METADATA_KEYS,
+            max_key_length: MAX_METADATA_KEY_LENGTH,
+            max_value_length: MAX_METADATA_VALUE_LENGTH,
+            max_bytes: MAX_METADATA_BYTES,
+        }
+    }
+}
+
+/// Checks `metadata` against the default [`MetadataLimits`].
+///
+/// # Errors
+///
+/// Returns [`Error::Validation`] naming the offending key.
+pub fn validate_metadata(metadata: &HashMap<String, serde_json::Value>) -> Result<()> {
+    validate_metadata_with_limits(metadata, &MetadataLimits::default())
+}
+
+/// Checks `metadata` against the given limits.
+///
+/// # Errors
+///
+/// Returns [`Error::Validation`] if there are too many keys, naming the
+/// first key (in sorted order) that is too long or has too long a value,
+/// or if the serialized map is too large.
+pub fn validate_metadata_with_limits(
+    metadata: &HashMap<String, serde_json::Value>,
+    limits: &MetadataLimits,
+) -> Result<()> {
+    if metadata.len() > limits.max_keys {
+        return Err(Error::Validation {
+            message: format!(
+                "Metadata has {} keys (maximum {})",
+                metadata.len(),
+                limits.max_keys
+            ),
+        });
+    }
+
+    let mut keys: Vec<&String> = metadata.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        if key.chars().count() > limits.max_key_length {
+            return Err(Error::Validation {
+                message: format!(
+                    "Metadata key '{}' exceeds maximum length ({} characters)",
+                    key, limits.max_key_length
+                ),
+            });
+        }
+
+        let value_length = match &metadata[key] {
+            serde_json::Value::String(value) => value.chars().count(),
+            value => value.to_string().chars().count(),
+        };
+        if value_length > limits.max_value_length {
+            return Err(Error::Validation {
+                message: format!(
+                    "Metadata value for '{}' exceeds maximum length ({} characters)",
+                    key, limits.max_value_length
+                ),
+            });
+        }
+    }
+
+    let size = serde_json::to_vec(metadata)?.len();
+    if size > limits.max_bytes {
+        return Err(Error::Validation {
+            message: format!(
+                "Metadata is {} bytes when serialized (maximum {})",
+                size, limits.max_bytes
+            ),
+        });
+    }
+
+    Ok(())
+}
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
 
 use crate::client::Sendly;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::validate::validate_phone;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -33,20 +36,15 @@ pub enum DeliveryStatus {
     Failed,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Channel {
+    #[default]
     Sms,
     Whatsapp,
     Email,
 }
 
-impl Default for Channel {
-    fn default() -> Self {
-        Channel::Sms
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Verification {
     pub id: String,
@@ -160,15 +158,51 @@ impl SendVerificationRequest {
         self.template_id = Some(id.into());
         self
     }
+
+    pub fn max_attempts(mut self, attempts: i32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    pub fn profile_id(mut self, id: impl Into<String>) -> Self {
+        self.profile_id = Some(id.into());
+        self
+    }
+
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    pub fn metadata(mut self, metadata: HashMap<String, serde_json::Value>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SendVerificationResponse {
     pub verification: Verification,
+    /// Only returned for sandbox verifications; prefer [`Self::sandbox_code`].
     #[serde(default)]
     pub code: Option<String>,
 }
 
+impl SendVerificationResponse {
+    /// Returns the code for sandbox verifications, so tests can complete the
+    /// flow without a real phone.
+    ///
+    /// Live verifications never expose the code: this is always `None` unless
+    /// `verification.sandbox` is true.
+    pub fn sandbox_code(&self) -> Option<&str> {
+        if self.verification.sandbox {
+            self.code.as_deref()
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct CheckVerificationRequest {
     pub code: String,
@@ -377,6 +411,8 @@ impl<'a> VerifyResource<'a> {
     }
 
     pub async fn send(&self, request: SendVerificationRequest) -> Result<SendVerificationResponse> {
+        validate_phone(&request.phone)?;
+
         let response = self.client.post("/verify", &request).await?;
         Ok(response.json().await?)
     }
@@ -405,6 +441,36 @@ impl<'a> VerifyResource<'a> {
         Ok(response.json().await?)
     }
 
+    /// Polls until the code has been delivered or delivery failed, checking
+    /// every `poll_interval`.
+    ///
+    /// Returns the verification in either final state; a failed delivery is
+    /// not an error. Returns [`Error::Timeout`] if neither happens within
+    /// `timeout`.
+    pub async fn wait_until_delivered(
+        &self,
+        id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Verification> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let verification = self.get(id).await?;
+            if matches!(
+                verification.delivery_status,
+                DeliveryStatus::Delivered | DeliveryStatus::Failed
+            ) {
+                return Ok(verification);
+            }
+
+            if Instant::now() + poll_interval > deadline {
+                return Err(Error::Timeout);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
     pub async fn list(&self, options: ListVerificationsOptions) -> Result<VerificationList> {
         let params = options.to_query_params();
         let response = self.client.get("/verify", &params).await?;
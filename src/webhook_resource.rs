@@ -1,14 +1,18 @@
 //! Webhooks resource for managing webhook endpoints.
 
 use crate::client::Sendly;
-use crate::error::Result;
+use crate::error::{unexpected_shape, Result};
 use crate::models::{
-    CreateWebhookRequest, ListDeliveriesOptions, UpdateWebhookRequest, Webhook,
-    WebhookCreatedResponse, WebhookDelivery, WebhookDeliveryList, WebhookSecretRotation,
-    WebhookTestResult,
+    CreateWebhookRequest, ListDeliveriesOptions, ListWebhooksOptions, RetryAllResult,
+    UpdateWebhookRequest, Webhook, WebhookCreatedResponse, WebhookDelivery, WebhookDeliveryList,
+    WebhookSecretRotation, WebhookTestResult,
 };
+use futures::{StreamExt, TryStreamExt};
 use serde::Deserialize;
 
+/// Maximum number of delivery retries issued concurrently by `retry_failed`.
+const RETRY_CONCURRENCY: usize = 5;
+
 /// Webhooks resource for managing webhook endpoints.
 pub struct WebhooksResource<'a> {
     client: &'a Sendly,
@@ -32,6 +36,15 @@ struct WebhookListResponse {
     data: Option<Vec<Webhook>>,
 }
 
+impl WebhookResponse {
+    fn into_webhook(self) -> Result<Webhook> {
+        self.webhook
+            .or(self.data)
+            .or(self.flat)
+            .ok_or_else(|| unexpected_shape("webhook"))
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct DeliveryResponse {
     #[serde(default)]
@@ -40,6 +53,14 @@ struct DeliveryResponse {
     data: Option<WebhookDelivery>,
 }
 
+impl DeliveryResponse {
+    fn into_delivery(self) -> Result<WebhookDelivery> {
+        self.delivery
+            .or(self.data)
+            .ok_or_else(|| unexpected_shape("webhook delivery"))
+    }
+}
+
 impl<'a> WebhooksResource<'a> {
     pub(crate) fn new(client: &'a Sendly) -> Self {
         Self { client }
@@ -66,7 +87,7 @@ impl<'a> WebhooksResource<'a> {
     /// ).await?;
     ///
     /// println!("Webhook created: {:?}", response.get_webhook());
-    /// println!("Secret: {}", response.secret);
+    /// println!("Secret: {}", response.secret.expose());
     /// # Ok(())
     /// # }
     /// ```
@@ -75,17 +96,36 @@ impl<'a> WebhooksResource<'a> {
         url: impl Into<String>,
         events: Vec<impl Into<String>>,
     ) -> Result<WebhookCreatedResponse> {
-        let request = CreateWebhookRequest {
-            url: url.into(),
-            events: events.into_iter().map(|e| e.into()).collect(),
-            mode: None,
-            api_version: None,
-        };
-
-        self.create_with_options(request).await
+        self.create_with_options(CreateWebhookRequest::new(url, events))
+            .await
     }
 
     /// Creates a new webhook with full options.
+    ///
+    /// Use this instead of [`create`](Self::create) to set the event mode or
+    /// pin the payload API version.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The webhook to create
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{CreateWebhookRequest, Sendly, WebhookMode};
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let request = CreateWebhookRequest::new("https://example.com/webhook", vec!["message.delivered"])
+    ///     .mode(WebhookMode::Live)
+    ///     .api_version("2024-01-01");
+    /// let response = client.webhooks().create_with_options(request).await?;
+    ///
+    /// println!("Webhook created: {:?}", response.get_webhook());
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn create_with_options(
         &self,
         request: CreateWebhookRequest,
@@ -113,10 +153,40 @@ impl<'a> WebhooksResource<'a> {
     /// # }
     /// ```
     pub async fn list(&self) -> Result<Vec<Webhook>> {
-        let response = self.client.get("/webhooks", &[]).await?;
+        self.list_with_options(ListWebhooksOptions::default()).await
+    }
+
+    /// Lists webhooks matching the given filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Filters applied server-side
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{ListWebhooksOptions, Sendly};
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let webhooks = client
+    ///     .webhooks()
+    ///     .list_with_options(ListWebhooksOptions::new().event("message.failed").active(true))
+    ///     .await?;
+    /// println!("{} active webhooks receive failures", webhooks.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_with_options(&self, options: ListWebhooksOptions) -> Result<Vec<Webhook>> {
+        let query = options.to_query_params();
+        let response = self.client.get("/webhooks", &query).await?;
         let result: WebhookListResponse = response.json().await?;
 
-        Ok(result.webhooks.or(result.data).unwrap_or_default())
+        result
+            .webhooks
+            .or(result.data)
+            .ok_or_else(|| unexpected_shape("webhook list"))
     }
 
     /// Gets a webhook by ID.
@@ -129,26 +199,7 @@ impl<'a> WebhooksResource<'a> {
         let response = self.client.get(&path, &[]).await?;
         let result: WebhookResponse = response.json().await?;
 
-        Ok(result
-            .webhook
-            .or(result.data)
-            .or(result.flat)
-            .unwrap_or_else(|| Webhook {
-                id: String::new(),
-                url: String::new(),
-                events: Vec::new(),
-                mode: crate::models::WebhookMode::All,
-                is_active: true,
-                failure_count: 0,
-                circuit_state: crate::models::CircuitState::Closed,
-                api_version: None,
-                total_deliveries: 0,
-                successful_deliveries: 0,
-                success_rate: 0.0,
-                last_delivery_at: None,
-                created_at: None,
-                updated_at: None,
-            }))
+        result.into_webhook()
     }
 
     /// Updates a webhook.
@@ -166,26 +217,7 @@ impl<'a> WebhooksResource<'a> {
         let response = self.client.patch(&path, &request).await?;
         let result: WebhookResponse = response.json().await?;
 
-        Ok(result
-            .webhook
-            .or(result.data)
-            .or(result.flat)
-            .unwrap_or_else(|| Webhook {
-                id: String::new(),
-                url: String::new(),
-                events: Vec::new(),
-                mode: crate::models::WebhookMode::All,
-                is_active: true,
-                failure_count: 0,
-                circuit_state: crate::models::CircuitState::Closed,
-                api_version: None,
-                total_deliveries: 0,
-                successful_deliveries: 0,
-                success_rate: 0.0,
-                last_delivery_at: None,
-                created_at: None,
-                updated_at: None,
-            }))
+        result.into_webhook()
     }
 
     /// Deletes a webhook.
@@ -211,6 +243,38 @@ impl<'a> WebhooksResource<'a> {
         Ok(result)
     }
 
+    /// Resets a webhook's circuit breaker so deliveries resume.
+    ///
+    /// The circuit opens after repeated delivery failures; call this once
+    /// the endpoint has recovered.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Webhook ID
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let webhook = client.webhooks().get("whk_abc123").await?;
+    /// if webhook.is_circuit_open() {
+    ///     client.webhooks().reset_circuit(&webhook.id).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reset_circuit(&self, id: impl AsRef<str>) -> Result<Webhook> {
+        let path = format!("/webhooks/{}/reset-circuit", id.as_ref());
+        let response = self.client.post(&path, &()).await?;
+        let result: WebhookResponse = response.json().await?;
+
+        result.into_webhook()
+    }
+
     /// Rotates a webhook's secret.
     ///
     /// # Arguments
@@ -241,6 +305,69 @@ impl<'a> WebhooksResource<'a> {
         Ok(result)
     }
 
+    /// Iterates over all delivery attempts for a webhook with automatic pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Webhook ID
+    /// * `options` - Optional query options (filters are kept across pages)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::{ListDeliveriesOptions, Sendly};
+    /// use futures::StreamExt;
+    /// use tokio::pin;
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    /// let webhooks = client.webhooks();
+    /// let stream = webhooks.iter_deliveries(
+    ///     "whk_abc123",
+    ///     Some(
+    ///         ListDeliveriesOptions::new()
+    ///             .success(false)
+    ///             .from("2025-01-15T00:00:00Z"),
+    ///     ),
+    /// );
+    /// pin!(stream);
+    /// while let Some(result) = stream.next().await {
+    ///     let delivery = result?;
+    ///     println!("{}: HTTP {}", delivery.id, delivery.http_status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_deliveries(
+        &self,
+        id: impl AsRef<str>,
+        options: Option<ListDeliveriesOptions>,
+    ) -> impl futures::Stream<Item = Result<WebhookDelivery>> + '_ {
+        let id = id.as_ref().to_string();
+        let options = options.unwrap_or_default();
+        let mut offset = options.offset.unwrap_or(0);
+        let batch_size = options.limit.filter(|&l| l > 0).unwrap_or(100);
+
+        async_stream::try_stream! {
+            loop {
+                let list_opts = options.clone().limit(batch_size).offset(offset);
+                let page = self.list_deliveries(&id, Some(list_opts)).await?;
+                let page_len = page.data.len();
+
+                for delivery in page.data {
+                    yield delivery;
+                }
+
+                // Stop if we got fewer results than requested
+                if page_len < batch_size as usize {
+                    break;
+                }
+
+                offset += batch_size;
+            }
+        }
+    }
+
     /// Gets a specific delivery attempt.
     ///
     /// # Arguments
@@ -260,20 +387,7 @@ impl<'a> WebhooksResource<'a> {
         let response = self.client.get(&path, &[]).await?;
         let result: DeliveryResponse = response.json().await?;
 
-        Ok(result
-            .delivery
-            .or(result.data)
-            .unwrap_or_else(|| WebhookDelivery {
-                id: String::new(),
-                webhook_id: String::new(),
-                event_type: String::new(),
-                http_status: 0,
-                success: false,
-                attempt_number: 1,
-                error_message: None,
-                response_time_ms: 0,
-                created_at: None,
-            }))
+        result.into_delivery()
     }
 
     /// Retries a failed delivery.
@@ -295,20 +409,68 @@ impl<'a> WebhooksResource<'a> {
         let response = self.client.post(&path, &()).await?;
         let result: DeliveryResponse = response.json().await?;
 
-        Ok(result
-            .delivery
-            .or(result.data)
-            .unwrap_or_else(|| WebhookDelivery {
-                id: String::new(),
-                webhook_id: String::new(),
-                event_type: String::new(),
-                http_status: 0,
-                success: false,
-                attempt_number: 1,
-                error_message: None,
-                response_time_ms: 0,
-                created_at: None,
-            }))
+        result.into_delivery()
+    }
+
+    /// Retries every failed delivery for a webhook.
+    ///
+    /// Failed deliveries are retried with bounded concurrency. A retry that
+    /// errors is counted as failed and does not abort the remaining retries.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Webhook ID
+    /// * `since` - Only retry deliveries created at or after this time (ISO 8601)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::Sendly;
+    ///
+    /// # async fn example() -> Result<(), sendly::Error> {
+    /// let client = Sendly::new("sk_live_v1_xxx");
+    ///
+    /// let result = client
+    ///     .webhooks()
+    ///     .retry_failed("whk_abc123", Some("2025-01-15T00:00:00Z".to_string()))
+    ///     .await?;
+    /// println!("{} retried, {} succeeded", result.retried, result.succeeded);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retry_failed(
+        &self,
+        id: impl AsRef<str>,
+        since: Option<String>,
+    ) -> Result<RetryAllResult> {
+        let id = id.as_ref();
+
+        let mut options = ListDeliveriesOptions::new().success(false);
+        if let Some(since) = since {
+            options = options.from(since);
+        }
+
+        let failed: Vec<WebhookDelivery> = self
+            .iter_deliveries(id, Some(options))
+            .try_collect()
+            .await?;
+
+        let outcomes: Vec<Result<WebhookDelivery>> = futures::stream::iter(failed)
+            .map(|delivery| async move { self.retry_delivery(id, &delivery.id).await })
+            .buffer_unordered(RETRY_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut result = RetryAllResult::default();
+        for outcome in outcomes {
+            result.retried += 1;
+            match outcome {
+                Ok(delivery) if delivery.success => result.succeeded += 1,
+                _ => result.failed += 1,
+            }
+        }
+
+        Ok(result)
     }
 
     /// Lists available webhook event types.
@@ -24,27 +24,135 @@
 //! }
 //! ```
 
+use futures::future::BoxFuture;
 use hmac::{Hmac, Mac};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use sha2::Sha256;
+use std::collections::HashMap;
+use std::future::Future;
+use subtle::ConstantTimeEq;
 use thiserror::Error;
 
+use crate::verify::{Channel, DeliveryStatus, VerificationStatus};
+
 type HmacSha256 = Hmac<Sha256>;
 
+/// Name of the header carrying the webhook signature
+pub const SIGNATURE_HEADER: &str = "x-sendly-signature";
+
 /// Webhook event types
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WebhookEventType {
-    #[serde(rename = "message.queued")]
     MessageQueued,
-    #[serde(rename = "message.sent")]
     MessageSent,
-    #[serde(rename = "message.delivered")]
     MessageDelivered,
-    #[serde(rename = "message.failed")]
     MessageFailed,
-    #[serde(rename = "message.undelivered")]
     MessageUndelivered,
+    VerificationCreated,
+    VerificationDelivered,
+    VerificationVerified,
+    VerificationFailed,
+    VerificationExpired,
+    /// A dotted event name (e.g. `contact.created`) this version of the SDK
+    /// has no variant for yet
+    Other(String),
+    /// A malformed event type that isn't a dotted event name
+    Unknown,
+}
+
+impl WebhookEventType {
+    /// Returns the wire name of the event, e.g. `message.delivered`
+    pub fn as_str(&self) -> &str {
+        match self {
+            WebhookEventType::MessageQueued => "message.queued",
+            WebhookEventType::MessageSent => "message.sent",
+            WebhookEventType::MessageDelivered => "message.delivered",
+            WebhookEventType::MessageFailed => "message.failed",
+            WebhookEventType::MessageUndelivered => "message.undelivered",
+            WebhookEventType::VerificationCreated => "verification.created",
+            WebhookEventType::VerificationDelivered => "verification.delivered",
+            WebhookEventType::VerificationVerified => "verification.verified",
+            WebhookEventType::VerificationFailed => "verification.failed",
+            WebhookEventType::VerificationExpired => "verification.expired",
+            WebhookEventType::Other(name) => name,
+            WebhookEventType::Unknown => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Self {
+        match name {
+            "message.queued" => WebhookEventType::MessageQueued,
+            "message.sent" => WebhookEventType::MessageSent,
+            "message.delivered" => WebhookEventType::MessageDelivered,
+            "message.failed" => WebhookEventType::MessageFailed,
+            "message.undelivered" => WebhookEventType::MessageUndelivered,
+            "verification.created" => WebhookEventType::VerificationCreated,
+            "verification.delivered" => WebhookEventType::VerificationDelivered,
+            "verification.verified" => WebhookEventType::VerificationVerified,
+            "verification.failed" => WebhookEventType::VerificationFailed,
+            "verification.expired" => WebhookEventType::VerificationExpired,
+            other if other.contains('.') => WebhookEventType::Other(other.to_string()),
+            _ => WebhookEventType::Unknown,
+        }
+    }
+
+    /// Returns true for `message.*` events
+    pub fn is_message_event(&self) -> bool {
+        matches!(
+            self,
+            WebhookEventType::MessageQueued
+                | WebhookEventType::MessageSent
+                | WebhookEventType::MessageDelivered
+                | WebhookEventType::MessageFailed
+                | WebhookEventType::MessageUndelivered
+        )
+    }
+
+    /// Returns true for `verification.*` events
+    pub fn is_verification_event(&self) -> bool {
+        matches!(
+            self,
+            WebhookEventType::VerificationCreated
+                | WebhookEventType::VerificationDelivered
+                | WebhookEventType::VerificationVerified
+                | WebhookEventType::VerificationFailed
+                | WebhookEventType::VerificationExpired
+        )
+    }
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<WebhookEventType> for String {
+    fn from(event_type: WebhookEventType) -> Self {
+        match event_type {
+            WebhookEventType::Other(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl Serialize for WebhookEventType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookEventType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        Ok(WebhookEventType::from_name(&name))
+    }
 }
 
 /// Message status in webhook events
@@ -87,8 +195,71 @@ pub struct WebhookMessageData {
     pub credits_used: i32,
 }
 
-/// Webhook event from Sendly
+/// Data payload for verification webhook events
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookVerificationData {
+    /// The verification ID
+    pub verification_id: String,
+    /// Phone number being verified
+    pub phone: String,
+    /// Current verification status
+    pub status: VerificationStatus,
+    /// Delivery status of the code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery_status: Option<DeliveryStatus>,
+    /// Channel the code was sent over
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Channel>,
+    /// Number of check attempts made
+    #[serde(default)]
+    pub attempts: i32,
+}
+
+/// Event data, shaped according to the event type
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum WebhookData {
+    /// Data for `message.*` events
+    Message(WebhookMessageData),
+    /// Data for `verification.*` events
+    Verification(WebhookVerificationData),
+    /// Raw data for event types without a typed payload
+    Unknown(serde_json::Value),
+}
+
+impl WebhookData {
+    fn from_event_type(
+        event_type: &WebhookEventType,
+        data: serde_json::Value,
+    ) -> Result<Self, serde_json::Error> {
+        if event_type.is_message_event() {
+            Ok(WebhookData::Message(serde_json::from_value(data)?))
+        } else if event_type.is_verification_event() {
+            Ok(WebhookData::Verification(serde_json::from_value(data)?))
+        } else {
+            Ok(WebhookData::Unknown(data))
+        }
+    }
+
+    /// Returns the message data, if this is a message event
+    pub fn as_message(&self) -> Option<&WebhookMessageData> {
+        match self {
+            WebhookData::Message(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns the verification data, if this is a verification event
+    pub fn as_verification(&self) -> Option<&WebhookVerificationData> {
+        match self {
+            WebhookData::Verification(data) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+/// Webhook event from Sendly
+#[derive(Debug, Clone, Serialize)]
 pub struct WebhookEvent {
     /// Unique event ID
     pub id: String,
@@ -96,14 +267,44 @@ pub struct WebhookEvent {
     #[serde(rename = "type")]
     pub event_type: WebhookEventType,
     /// Event data
-    pub data: WebhookMessageData,
+    pub data: WebhookData,
     /// When the event was created (ISO 8601)
     pub created_at: String,
     /// API version
-    #[serde(default = "default_api_version")]
     pub api_version: String,
 }
 
+impl<'de> Deserialize<'de> for WebhookEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawWebhookEvent {
+            id: String,
+            #[serde(rename = "type")]
+            event_type: WebhookEventType,
+            #[serde(default)]
+            data: serde_json::Value,
+            created_at: String,
+            #[serde(default = "default_api_version")]
+            api_version: String,
+        }
+
+        let raw = RawWebhookEvent::deserialize(deserializer)?;
+        let data = WebhookData::from_event_type(&raw.event_type, raw.data)
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(WebhookEvent {
+            id: raw.id,
+            event_type: raw.event_type,
+            data,
+            created_at: raw.created_at,
+            api_version: raw.api_version,
+        })
+    }
+}
+
 fn default_api_version() -> String {
     "2024-01-01".to_string()
 }
@@ -147,6 +348,37 @@ impl Webhooks {
     /// let is_valid = Webhooks::verify_signature(raw_body, signature, secret);
     /// ```
     pub fn verify_signature(payload: &str, signature: &str, secret: &str) -> bool {
+        Self::verify_signature_bytes(payload.as_bytes(), signature, secret)
+    }
+
+    /// Verify webhook signature over the raw request body bytes
+    ///
+    /// Sendly signs the exact bytes it sends, so prefer this over
+    /// [`Webhooks::verify_signature`] when your framework hands you the body
+    /// as `Bytes`/`Vec<u8>`; it avoids re-encoding the payload before hashing.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Raw request body as bytes
+    /// * `signature` - X-Sendly-Signature header value
+    /// * `secret` - Your webhook secret from dashboard
+    ///
+    /// # Returns
+    ///
+    /// `true` if signature is valid, `false` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::webhooks::Webhooks;
+    ///
+    /// let raw_body: &[u8] = br#"{"id":"evt_123","type":"message.delivered"}"#;
+    /// let signature = "sha256=abc123";
+    /// let secret = "your_webhook_secret";
+    ///
+    /// let is_valid = Webhooks::verify_signature_bytes(raw_body, signature, secret);
+    /// ```
+    pub fn verify_signature_bytes(payload: &[u8], signature: &str, secret: &str) -> bool {
         if payload.is_empty() || signature.is_empty() || secret.is_empty() {
             return false;
         }
@@ -156,12 +388,58 @@ impl Webhooks {
             Err(_) => return false,
         };
 
-        mac.update(payload.as_bytes());
-        let result = mac.finalize();
-        let expected = format!("sha256={}", hex::encode(result.into_bytes()));
+        let provided = match signature
+            .strip_prefix("sha256=")
+            .and_then(|hex_sig| hex::decode(hex_sig).ok())
+        {
+            Some(bytes) => bytes,
+            None => return false,
+        };
+
+        mac.update(payload);
+        let expected = mac.finalize().into_bytes();
 
-        // Constant-time comparison
-        constant_time_compare(signature, &expected)
+        // Constant-time comparison of the raw HMAC bytes
+        provided.len() == expected.len() && bool::from(expected.as_slice().ct_eq(&provided))
+    }
+
+    /// Verify webhook signature against several secrets
+    ///
+    /// Returns `true` if any of `secrets` produces a matching signature. Every
+    /// secret is checked, each with a constant-time comparison, so the time
+    /// taken does not reveal which secret matched.
+    ///
+    /// # Secret rotation
+    ///
+    /// Deliveries already in flight when you call
+    /// `WebhooksResource::rotate_secret` may still be signed with the old
+    /// secret. To rotate without dropping events:
+    ///
+    /// 1. Rotate the secret and store the new value alongside the old one.
+    /// 2. Verify incoming events with both secrets, new one first.
+    /// 3. Once the grace period has passed, remove the old secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Raw request body as string
+    /// * `signature` - X-Sendly-Signature header value
+    /// * `secrets` - Candidate webhook secrets
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sendly::webhooks::Webhooks;
+    ///
+    /// let raw_body = r#"{"id":"evt_123","type":"message.delivered"}"#;
+    /// let signature = "sha256=abc123";
+    ///
+    /// let is_valid =
+    ///     Webhooks::verify_signature_multi(raw_body, signature, &["new_secret", "old_secret"]);
+    /// ```
+    pub fn verify_signature_multi(payload: &str, signature: &str, secrets: &[&str]) -> bool {
+        secrets.iter().fold(false, |valid, secret| {
+            Self::verify_signature(payload, signature, secret) | valid
+        })
     }
 
     /// Parse and validate a webhook event
@@ -188,7 +466,9 @@ impl Webhooks {
     /// match Webhooks::parse_event(raw_body, signature, secret) {
     ///     Ok(event) => {
     ///         println!("Event type: {:?}", event.event_type);
-    ///         println!("Message ID: {}", event.data.message_id);
+    ///         if let Some(data) = event.data.as_message() {
+    ///             println!("Message ID: {}", data.message_id);
+    ///         }
     ///     }
     ///     Err(e) => eprintln!("Error: {}", e),
     /// }
@@ -198,12 +478,69 @@ impl Webhooks {
         signature: &str,
         secret: &str,
     ) -> Result<WebhookEvent, WebhookError> {
-        if !Self::verify_signature(payload, signature, secret) {
+        Self::parse_event_bytes(payload.as_bytes(), signature, secret)
+    }
+
+    /// Parse and validate a webhook event from the raw request body bytes
+    ///
+    /// The signature is checked against the bytes exactly as received, before
+    /// any UTF-8 decoding takes place.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Raw request body as bytes
+    /// * `signature` - X-Sendly-Signature header value
+    /// * `secret` - Your webhook secret from dashboard
+    ///
+    /// # Returns
+    ///
+    /// Parsed and validated `WebhookEvent` or an error
+    pub fn parse_event_bytes(
+        payload: &[u8],
+        signature: &str,
+        secret: &str,
+    ) -> Result<WebhookEvent, WebhookError> {
+        if !Self::verify_signature_bytes(payload, signature, secret) {
             return Err(WebhookError::InvalidSignature);
         }
 
+        Self::parse_payload(payload)
+    }
+
+    /// Parse a webhook event WITHOUT verifying its signature
+    ///
+    /// Runs the same structural validation as [`Webhooks::parse_event`] but
+    /// skips the HMAC check, so tests can build events from hand-written
+    /// payloads.
+    ///
+    /// **Never use this on untrusted input in production.** Anyone can send a
+    /// request to your webhook endpoint; only [`Webhooks::parse_event`] and
+    /// friends prove that it came from Sendly.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Raw request body as string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sendly::webhooks::{WebhookEventType, Webhooks};
+    ///
+    /// let raw_body = r#"{"id":"evt_123","type":"campaign.completed","data":{},"created_at":"2024-01-01"}"#;
+    ///
+    /// let event = Webhooks::parse_event_unverified(raw_body).unwrap();
+    /// assert_eq!(
+    ///     event.event_type,
+    ///     WebhookEventType::Other("campaign.completed".to_string())
+    /// );
+    /// ```
+    pub fn parse_event_unverified(payload: &str) -> Result<WebhookEvent, WebhookError> {
+        Self::parse_payload(payload.as_bytes())
+    }
+
+    fn parse_payload(payload: &[u8]) -> Result<WebhookEvent, WebhookError> {
         let event: WebhookEvent =
-            serde_json::from_str(payload).map_err(|e| WebhookError::ParseError(e.to_string()))?;
+            serde_json::from_slice(payload).map_err(|e| WebhookError::ParseError(e.to_string()))?;
 
         // Basic validation
         if event.id.is_empty() || event.created_at.is_empty() {
@@ -213,6 +550,51 @@ impl Webhooks {
         Ok(event)
     }
 
+    /// Verify and parse a webhook event, reading the signature from the request headers
+    ///
+    /// Looks up the `X-Sendly-Signature` header (header names are
+    /// case-insensitive) so framework handlers can pass their `HeaderMap`
+    /// straight through.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Raw request body as string
+    /// * `headers` - Request headers
+    /// * `secret` - Your webhook secret from dashboard
+    ///
+    /// # Returns
+    ///
+    /// Parsed and validated `WebhookEvent`, or `WebhookError::InvalidStructure`
+    /// if the signature header is missing
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use http::HeaderMap;
+    /// use sendly::webhooks::Webhooks;
+    ///
+    /// # fn handle(body: &str, headers: &HeaderMap) {
+    /// let secret = "your_webhook_secret";
+    ///
+    /// match Webhooks::construct_event(body, headers, secret) {
+    ///     Ok(event) => println!("Event type: {:?}", event.event_type),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// # }
+    /// ```
+    pub fn construct_event(
+        payload: &str,
+        headers: &http::HeaderMap,
+        secret: &str,
+    ) -> Result<WebhookEvent, WebhookError> {
+        let signature = headers
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(WebhookError::InvalidStructure)?;
+
+        Self::parse_event(payload, signature, secret)
+    }
+
     /// Generate a webhook signature for testing purposes
     ///
     /// # Arguments
@@ -242,17 +624,204 @@ impl Webhooks {
     }
 }
 
-/// Constant-time string comparison to prevent timing attacks
-fn constant_time_compare(a: &str, b: &str) -> bool {
-    if a.len() != b.len() {
-        return false;
+type WebhookHandlerFn<E> =
+    Box<dyn Fn(WebhookEvent) -> BoxFuture<'static, Result<(), E>> + Send + Sync>;
+
+/// Routes webhook events to async handlers registered per event type
+///
+/// # Example
+///
+/// ```rust
+/// use sendly::webhooks::{WebhookEvent, WebhookEventType, WebhookRouter};
+///
+/// # async fn example(event: WebhookEvent) -> Result<(), String> {
+/// let router = WebhookRouter::new()
+///     .on(WebhookEventType::MessageDelivered, |event: WebhookEvent| async move {
+///         println!("Delivered: {}", event.id);
+///         Ok(())
+///     })
+///     .on(WebhookEventType::MessageFailed, |event: WebhookEvent| async move {
+///         Err(format!("Message failed: {}", event.id))
+///     })
+///     .fallback(|event: WebhookEvent| async move {
+///         println!("Unhandled event: {:?}", event.event_type);
+///         Ok(())
+///     });
+///
+/// router.dispatch(event).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct WebhookRouter<E> {
+    handlers: HashMap<WebhookEventType, WebhookHandlerFn<E>>,
+    fallback: Option<WebhookHandlerFn<E>>,
+}
+
+impl<E> WebhookRouter<E> {
+    /// Create an empty router
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            fallback: None,
+        }
+    }
+
+    /// Register a handler for an event type, replacing any previous one
+    pub fn on<F, Fut>(mut self, event_type: WebhookEventType, handler: F) -> Self
+    where
+        F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        self.handlers.insert(event_type, boxed_handler(handler));
+        self
+    }
+
+    /// Register a catch-all handler for event types without their own handler
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        self.fallback = Some(boxed_handler(handler));
+        self
+    }
+
+    /// Returns true if a dedicated handler is registered for the event type
+    pub fn handles(&self, event_type: &WebhookEventType) -> bool {
+        self.handlers.contains_key(event_type)
+    }
+
+    /// Dispatch an event to its handler
+    ///
+    /// Falls back to the catch-all handler when no handler is registered for
+    /// the event type; events with neither are ignored.
+    pub async fn dispatch(&self, event: WebhookEvent) -> Result<(), E> {
+        match self
+            .handlers
+            .get(&event.event_type)
+            .or(self.fallback.as_ref())
+        {
+            Some(handler) => handler(event).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl<E> Default for WebhookRouter<E> {
+    fn default() -> Self {
+        Self::new()
     }
+}
+
+fn boxed_handler<E, F, Fut>(handler: F) -> WebhookHandlerFn<E>
+where
+    F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+{
+    Box::new(move |event| Box::pin(handler(event)))
+}
+
+/// Typed callbacks for webhook events, dispatched by [`Webhooks::handle`]
+///
+/// Every method defaults to a no-op, so implementors only override the
+/// events they care about.
+///
+/// # Example
+///
+/// ```rust
+/// use sendly::webhooks::{WebhookEvent, WebhookHandler, WebhookMessageData, Webhooks};
+///
+/// struct App;
+///
+/// #[async_trait::async_trait]
+/// impl WebhookHandler for App {
+///     async fn on_delivered(&self, data: &WebhookMessageData) {
+///         println!("Delivered: {}", data.message_id);
+///     }
+/// }
+///
+/// # async fn example(event: WebhookEvent) {
+/// Webhooks::handle(&event, &App).await;
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait WebhookHandler: Send + Sync {
+    /// Called for `message.queued`
+    async fn on_queued(&self, _data: &WebhookMessageData) {}
+
+    /// Called for `message.sent`
+    async fn on_sent(&self, _data: &WebhookMessageData) {}
+
+    /// Called for `message.delivered`
+    async fn on_delivered(&self, _data: &WebhookMessageData) {}
 
-    let mut result = 0u8;
-    for (x, y) in a.bytes().zip(b.bytes()) {
-        result |= x ^ y;
+    /// Called for `message.failed`
+    async fn on_failed(&self, _data: &WebhookMessageData) {}
+
+    /// Called for `message.undelivered`
+    async fn on_undelivered(&self, _data: &WebhookMessageData) {}
+
+    /// Called for `verification.created`
+    async fn on_verification_created(&self, _data: &WebhookVerificationData) {}
+
+    /// Called for `verification.delivered`
+    async fn on_verification_delivered(&self, _data: &WebhookVerificationData) {}
+
+    /// Called for `verification.verified`
+    async fn on_verification_verified(&self, _data: &WebhookVerificationData) {}
+
+    /// Called for `verification.failed`
+    async fn on_verification_failed(&self, _data: &WebhookVerificationData) {}
+
+    /// Called for `verification.expired`
+    async fn on_verification_expired(&self, _data: &WebhookVerificationData) {}
+
+    /// Called for event types without a typed callback
+    async fn on_unknown(&self, _event: &WebhookEvent) {}
+}
+
+impl Webhooks {
+    /// Dispatch an already-verified event to the matching handler method
+    ///
+    /// Events whose data doesn't match their type go to
+    /// [`WebhookHandler::on_unknown`].
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - Event returned by [`Webhooks::parse_event`] or similar
+    /// * `handler` - Handler to invoke
+    pub async fn handle<H>(event: &WebhookEvent, handler: &H)
+    where
+        H: WebhookHandler + ?Sized,
+    {
+        use WebhookEventType as T;
+
+        match (&event.event_type, &event.data) {
+            (T::MessageQueued, WebhookData::Message(data)) => handler.on_queued(data).await,
+            (T::MessageSent, WebhookData::Message(data)) => handler.on_sent(data).await,
+            (T::MessageDelivered, WebhookData::Message(data)) => handler.on_delivered(data).await,
+            (T::MessageFailed, WebhookData::Message(data)) => handler.on_failed(data).await,
+            (T::MessageUndelivered, WebhookData::Message(data)) => {
+                handler.on_undelivered(data).await
+            }
+            (T::VerificationCreated, WebhookData::Verification(data)) => {
+                handler.on_verification_created(data).await
+            }
+            (T::VerificationDelivered, WebhookData::Verification(data)) => {
+                handler.on_verification_delivered(data).await
+            }
+            (T::VerificationVerified, WebhookData::Verification(data)) => {
+                handler.on_verification_verified(data).await
+            }
+            (T::VerificationFailed, WebhookData::Verification(data)) => {
+                handler.on_verification_failed(data).await
+            }
+            (T::VerificationExpired, WebhookData::Verification(data)) => {
+                handler.on_verification_expired(data).await
+            }
+            _ => handler.on_unknown(event).await,
+        }
     }
-    result == 0
 }
 
 #[cfg(test)]
@@ -269,6 +838,29 @@ mod tests {
         assert!(!Webhooks::verify_signature(payload, "invalid", secret));
     }
 
+    #[test]
+    fn test_verify_signature_last_byte_differs() {
+        let payload = r#"{"id":"evt_123","type":"message.delivered"}"#;
+        let secret = "test_secret";
+        let signature = Webhooks::generate_signature(payload, secret);
+
+        let last = signature.chars().last().unwrap();
+        let flipped = if last == '0' { '1' } else { '0' };
+        let tampered = format!("{}{}", &signature[..signature.len() - 1], flipped);
+
+        assert!(!Webhooks::verify_signature(payload, &tampered, secret));
+        assert!(!Webhooks::verify_signature(
+            payload,
+            &signature[..signature.len() - 2],
+            secret
+        ));
+        assert!(!Webhooks::verify_signature(
+            payload,
+            &signature["sha256=".len()..],
+            secret
+        ));
+    }
+
     #[test]
     fn test_generate_signature() {
         let payload = "test";
@@ -278,4 +870,371 @@ mod tests {
         assert!(signature.starts_with("sha256="));
         assert_eq!(signature.len(), 71); // "sha256=" + 64 hex chars
     }
+
+    #[test]
+    fn test_verify_signature_bytes_non_ascii() {
+        let payload = r#"{"id":"evt_123","type":"message.delivered","text":"Olá, 世界 👋"}"#;
+        let secret = "test_secret";
+        let signature = Webhooks::generate_signature(payload, secret);
+
+        assert!(Webhooks::verify_signature_bytes(
+            payload.as_bytes(),
+            &signature,
+            secret
+        ));
+        assert!(Webhooks::verify_signature(payload, &signature, secret));
+
+        // Re-encoding the body (e.g. lossy UTF-8 handling) must not verify.
+        let mut tampered = payload.as_bytes().to_vec();
+        tampered.retain(|b| b.is_ascii());
+        assert!(!Webhooks::verify_signature_bytes(
+            &tampered, &signature, secret
+        ));
+    }
+
+    #[test]
+    fn test_parse_event_bytes() {
+        let payload = r#"{"id":"evt_123","type":"message.delivered","data":{"message_id":"msg_1","status":"delivered","to":"+15551234567","from":"Café","segments":1,"credits_used":1},"created_at":"2025-01-15T10:00:00Z"}"#;
+        let secret = "test_secret";
+        let signature = Webhooks::generate_signature(payload, secret);
+
+        let event = Webhooks::parse_event_bytes(payload.as_bytes(), &signature, secret).unwrap();
+        assert_eq!(event.id, "evt_123");
+        assert_eq!(event.data.as_message().unwrap().from, "Café");
+
+        assert!(matches!(
+            Webhooks::parse_event_bytes(payload.as_bytes(), "sha256=bad", secret),
+            Err(WebhookError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn test_verify_signature_multi() {
+        let payload = r#"{"id":"evt_123","type":"message.delivered"}"#;
+        let signature = Webhooks::generate_signature(payload, "old_secret");
+
+        assert!(Webhooks::verify_signature_multi(
+            payload,
+            &signature,
+            &["new_secret", "old_secret"]
+        ));
+        assert!(!Webhooks::verify_signature_multi(
+            payload,
+            &signature,
+            &["new_secret", "other_secret"]
+        ));
+        assert!(!Webhooks::verify_signature_multi(payload, &signature, &[]));
+    }
+
+    #[test]
+    fn test_parse_verification_event() {
+        let payload = r#"{"id":"evt_456","type":"verification.verified","data":{"verification_id":"ver_1","phone":"+15551234567","status":"verified","delivery_status":"delivered","channel":"sms","attempts":1},"created_at":"2025-01-15T10:00:00Z"}"#;
+        let secret = "test_secret";
+        let signature = Webhooks::generate_signature(payload, secret);
+
+        let event = Webhooks::parse_event(payload, &signature, secret).unwrap();
+        assert_eq!(event.event_type, WebhookEventType::VerificationVerified);
+        assert!(event.data.as_message().is_none());
+
+        let data = event.data.as_verification().unwrap();
+        assert_eq!(data.verification_id, "ver_1");
+        assert_eq!(data.status, VerificationStatus::Verified);
+        assert_eq!(data.delivery_status, Some(DeliveryStatus::Delivered));
+    }
+
+    #[test]
+    fn test_parse_unknown_event() {
+        let payload = r#"{"id":"evt_789","type":"campaign.completed","data":{"campaign_id":"cmp_1","sent":42},"created_at":"2025-01-15T10:00:00Z"}"#;
+        let secret = "test_secret";
+        let signature = Webhooks::generate_signature(payload, secret);
+
+        let event = Webhooks::parse_event(payload, &signature, secret).unwrap();
+        assert_eq!(
+            event.event_type,
+            WebhookEventType::Other("campaign.completed".to_string())
+        );
+
+        match event.data {
+            WebhookData::Unknown(value) => {
+                assert_eq!(value["campaign_id"], "cmp_1");
+                assert_eq!(value["sent"], 42);
+            }
+            other => panic!("Expected unknown data, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_event_type_serde_round_trip() {
+        for (event_type, name) in [
+            (WebhookEventType::MessageDelivered, "message.delivered"),
+            (
+                WebhookEventType::Other("contact.created".to_string()),
+                "contact.created",
+            ),
+        ] {
+            let json = serde_json::to_value(&event_type).unwrap();
+            assert_eq!(json, serde_json::json!(name));
+
+            let parsed: WebhookEventType = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, event_type);
+        }
+    }
+
+    #[test]
+    fn test_event_type_non_dotted_is_unknown() {
+        let parsed: WebhookEventType = serde_json::from_str(r#""garbage""#).unwrap();
+        assert_eq!(parsed, WebhookEventType::Unknown);
+    }
+
+    #[test]
+    fn test_event_type_into_string() {
+        let events: Vec<String> = vec![
+            WebhookEventType::MessageFailed.into(),
+            WebhookEventType::Other("contact.created".to_string()).into(),
+        ];
+        assert_eq!(events, ["message.failed", "contact.created"]);
+    }
+
+    #[test]
+    fn test_construct_event_reads_signature_header() {
+        let payload = r#"{"id":"evt_123","type":"message.delivered","data":{"message_id":"msg_1","status":"delivered","to":"+15551234567","from":"ACME","segments":1,"credits_used":1},"created_at":"2025-01-15T10:00:00Z"}"#;
+        let secret = "test_secret";
+        let signature = Webhooks::generate_signature(payload, secret);
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::HeaderName::from_static("x-sendly-signature"),
+            signature.parse().unwrap(),
+        );
+
+        let event = Webhooks::construct_event(payload, &headers, secret).unwrap();
+        assert_eq!(event.id, "evt_123");
+
+        // Lookup is case-insensitive
+        let mut upper = http::HeaderMap::new();
+        upper.insert("X-Sendly-Signature", signature.parse().unwrap());
+        assert!(Webhooks::construct_event(payload, &upper, secret).is_ok());
+    }
+
+    #[test]
+    fn test_construct_event_missing_header() {
+        let payload =
+            r#"{"id":"evt_123","type":"message.delivered","created_at":"2025-01-15T10:00:00Z"}"#;
+
+        let result = Webhooks::construct_event(payload, &http::HeaderMap::new(), "test_secret");
+        assert!(matches!(result, Err(WebhookError::InvalidStructure)));
+    }
+
+    #[test]
+    fn test_parse_event_unverified() {
+        let payload = r#"{"id":"evt_123","type":"message.failed","data":{"message_id":"msg_1","status":"failed","to":"+15551234567","from":"ACME","error":"unroutable","segments":1,"credits_used":0},"created_at":"2025-01-15T10:00:00Z"}"#;
+
+        let event = Webhooks::parse_event_unverified(payload).unwrap();
+        assert_eq!(event.event_type, WebhookEventType::MessageFailed);
+        assert_eq!(
+            event.data.as_message().unwrap().error.as_deref(),
+            Some("unroutable")
+        );
+
+        // Structural validation still applies
+        let missing_id = r#"{"id":"","type":"campaign.completed","data":{},"created_at":"2025-01-15T10:00:00Z"}"#;
+        assert!(matches!(
+            Webhooks::parse_event_unverified(missing_id),
+            Err(WebhookError::InvalidStructure)
+        ));
+        assert!(matches!(
+            Webhooks::parse_event_unverified("not json"),
+            Err(WebhookError::ParseError(_))
+        ));
+    }
+
+    fn message_event(id: &str, event_type: &str) -> WebhookEvent {
+        let payload = format!(
+            r#"{{"id":"{}","type":"{}","data":{{"message_id":"msg_1","status":"delivered","to":"+15551234567","from":"SENDLY","segments":1,"credits_used":1}},"created_at":"2025-01-15T10:00:00Z"}}"#,
+            id, event_type
+        );
+        serde_json::from_str(&payload).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_router_dispatch() {
+        use std::sync::{Arc, Mutex};
+
+        let seen: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let delivered = seen.clone();
+        let failed = seen.clone();
+        let fallback = seen.clone();
+
+        let router: WebhookRouter<String> = WebhookRouter::new()
+            .on(WebhookEventType::MessageDelivered, move |event| {
+                let seen = delivered.clone();
+                async move {
+                    seen.lock().unwrap().push(format!("delivered:{}", event.id));
+                    Ok(())
+                }
+            })
+            .on(WebhookEventType::MessageFailed, move |event| {
+                let seen = failed.clone();
+                async move {
+                    seen.lock().unwrap().push(format!("failed:{}", event.id));
+                    Err("boom".to_string())
+                }
+            })
+            .fallback(move |event| {
+                let seen = fallback.clone();
+                async move {
+                    seen.lock().unwrap().push(format!("fallback:{}", event.id));
+                    Ok(())
+                }
+            });
+
+        assert!(router.handles(&WebhookEventType::MessageDelivered));
+        assert!(!router.handles(&WebhookEventType::MessageSent));
+
+        assert!(router
+            .dispatch(message_event("evt_1", "message.delivered"))
+            .await
+            .is_ok());
+        assert_eq!(
+            router
+                .dispatch(message_event("evt_2", "message.failed"))
+                .await,
+            Err("boom".to_string())
+        );
+        assert!(router
+            .dispatch(message_event("evt_3", "message.sent"))
+            .await
+            .is_ok());
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec!["delivered:evt_1", "failed:evt_2", "fallback:evt_3"]
+        );
+    }
+
+    fn verification_event(id: &str, event_type: &str) -> WebhookEvent {
+        let payload = format!(
+            r#"{{"id":"{}","type":"{}","data":{{"verification_id":"ver_1","phone":"+15551234567","status":"pending","attempts":0}},"created_at":"2025-01-15T10:00:00Z"}}"#,
+            id, event_type
+        );
+        serde_json::from_str(&payload).unwrap()
+    }
+
+    #[derive(Default)]
+    struct RecordingHandler {
+        seen: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl RecordingHandler {
+        fn record(&self, name: &str, id: &str) {
+            self.seen.lock().unwrap().push(format!("{}:{}", name, id));
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl WebhookHandler for RecordingHandler {
+        async fn on_queued(&self, data: &WebhookMessageData) {
+            self.record("queued", &data.message_id);
+        }
+
+        async fn on_sent(&self, data: &WebhookMessageData) {
+            self.record("sent", &data.message_id);
+        }
+
+        async fn on_delivered(&self, data: &WebhookMessageData) {
+            self.record("delivered", &data.message_id);
+        }
+
+        async fn on_failed(&self, data: &WebhookMessageData) {
+            self.record("failed", &data.message_id);
+        }
+
+        async fn on_undelivered(&self, data: &WebhookMessageData) {
+            self.record("undelivered", &data.message_id);
+        }
+
+        async fn on_verification_created(&self, data: &WebhookVerificationData) {
+            self.record("verification_created", &data.verification_id);
+        }
+
+        async fn on_verification_delivered(&self, data: &WebhookVerificationData) {
+            self.record("verification_delivered", &data.verification_id);
+        }
+
+        async fn on_verification_verified(&self, data: &WebhookVerificationData) {
+            self.record("verification_verified", &data.verification_id);
+        }
+
+        async fn on_verification_failed(&self, data: &WebhookVerificationData) {
+            self.record("verification_failed", &data.verification_id);
+        }
+
+        async fn on_verification_expired(&self, data: &WebhookVerificationData) {
+            self.record("verification_expired", &data.verification_id);
+        }
+
+        async fn on_unknown(&self, event: &WebhookEvent) {
+            self.record("unknown", &event.id);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_dispatches_each_event_type() {
+        let handler = RecordingHandler::default();
+
+        let events = [
+            message_event("evt_1", "message.queued"),
+            message_event("evt_2", "message.sent"),
+            message_event("evt_3", "message.delivered"),
+            message_event("evt_4", "message.failed"),
+            message_event("evt_5", "message.undelivered"),
+            verification_event("evt_6", "verification.created"),
+            verification_event("evt_7", "verification.delivered"),
+            verification_event("evt_8", "verification.verified"),
+            verification_event("evt_9", "verification.failed"),
+            verification_event("evt_10", "verification.expired"),
+            serde_json::from_str(
+                r#"{"id":"evt_11","type":"campaign.completed","data":{},"created_at":"2025-01-15T10:00:00Z"}"#,
+            )
+            .unwrap(),
+        ];
+        for event in &events {
+            Webhooks::handle(event, &handler).await;
+        }
+
+        assert_eq!(
+            *handler.seen.lock().unwrap(),
+            vec![
+                "queued:msg_1",
+                "sent:msg_1",
+                "delivered:msg_1",
+                "failed:msg_1",
+                "undelivered:msg_1",
+                "verification_created:ver_1",
+                "verification_delivered:ver_1",
+                "verification_verified:ver_1",
+                "verification_failed:ver_1",
+                "verification_expired:ver_1",
+                "unknown:evt_11",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_default_methods_are_noops() {
+        struct DeliveredOnly(std::sync::Mutex<u32>);
+
+        #[async_trait::async_trait]
+        impl WebhookHandler for DeliveredOnly {
+            async fn on_delivered(&self, _data: &WebhookMessageData) {
+                *self.0.lock().unwrap() += 1;
+            }
+        }
+
+        let handler = DeliveredOnly(std::sync::Mutex::new(0));
+        Webhooks::handle(&message_event("evt_1", "message.failed"), &handler).await;
+        Webhooks::handle(&message_event("evt_2", "message.delivered"), &handler).await;
+
+        assert_eq!(*handler.0.lock().unwrap(), 1);
+    }
 }
@@ -0,0 +1,344 @@
+mod common;
+
+use common::{create_test_client, setup_mock_server};
+use futures::StreamExt;
+use sendly::{
+    Credits, Currency, Error, ListTransactionsOptions, TransactionType, UpdateApiKeyRequest,
+};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+// ==================== iter_transactions() Tests ====================
+
+#[tokio::test]
+async fn test_iter_transactions_pagination() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/account/transactions"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "0"))
+        .and(query_param("type", "usage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "transactions": [
+                {"id": "tx_1", "type": "usage", "amount": -1, "balanceAfter": 99},
+                {"id": "tx_2", "type": "usage", "amount": -2, "balanceAfter": 97}
+            ],
+            "total": 3,
+            "hasMore": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/account/transactions"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "2"))
+        .and(query_param("type", "usage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "transactions": [
+                {"id": "tx_3", "type": "usage", "amount": -1, "balanceAfter": 96}
+            ],
+            "total": 3,
+            "hasMore": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListTransactionsOptions::new()
+        .limit(2)
+        .transaction_type(TransactionType::Usage);
+    let account = client.account();
+    let stream = account.iter_transactions(Some(options));
+    futures::pin_mut!(stream);
+    let mut transactions = Vec::new();
+
+    while let Some(result) = stream.next().await {
+        transactions.push(result.unwrap());
+    }
+
+    assert_eq!(transactions.len(), 3);
+    assert_eq!(transactions[0].id, "tx_1");
+    assert_eq!(transactions[2].id, "tx_3");
+    assert!(transactions.iter().all(|tx| tx.is_debit()));
+}
+
+// ==================== Credits Helper Tests ====================
+
+fn credits_with_available(available_balance: i32) -> Credits {
+    Credits {
+        balance: available_balance,
+        available_balance,
+        pending_credits: 0,
+        reserved_credits: 0,
+        currency: Currency::Usd,
+    }
+}
+
+#[test]
+fn test_credits_is_low() {
+    let credits = credits_with_available(50);
+
+    assert!(credits.is_low(100));
+    assert!(!credits.is_low(50));
+    assert!(!credits.is_low(10));
+}
+
+#[test]
+fn test_credits_can_afford() {
+    let credits = credits_with_available(50);
+
+    assert!(credits.can_afford(50));
+    assert!(credits.can_afford(0));
+    assert!(!credits.can_afford(51));
+}
+
+// ==================== ensure_credits() Tests ====================
+
+#[tokio::test]
+async fn test_ensure_credits() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/account/credits"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "balance": 120,
+            "availableBalance": 100,
+            "pendingCredits": 0,
+            "reservedCredits": 20
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    assert!(client.account().ensure_credits(100).await.is_ok());
+
+    let result = client.account().ensure_credits(101).await;
+    assert!(matches!(result, Err(Error::InsufficientCredits { .. })));
+}
+
+// ==================== update_api_key() Tests ====================
+
+#[tokio::test]
+async fn test_update_api_key_rename() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/account/keys/key_abc123"))
+        .and(body_json(json!({ "name": "Staging" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "apiKey": {
+                "id": "key_abc123",
+                "name": "Staging",
+                "prefix": "sk_live_v1_ab",
+                "isActive": true
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let key = client
+        .account()
+        .update_api_key("key_abc123", UpdateApiKeyRequest::new().name("Staging"))
+        .await
+        .unwrap();
+
+    assert_eq!(key.id, "key_abc123");
+    assert_eq!(key.name, "Staging");
+}
+
+#[tokio::test]
+async fn test_update_api_key_expiry() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/account/keys/key_abc123"))
+        .and(body_json(json!({ "expires_at": "2026-01-01T00:00:00Z" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "key_abc123",
+            "name": "Production",
+            "expiresAt": "2026-01-01T00:00:00Z"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let key = client
+        .account()
+        .update_api_key(
+            "key_abc123",
+            UpdateApiKeyRequest::new().expires_at("2026-01-01T00:00:00Z"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(key.expires_at.as_deref(), Some("2026-01-01T00:00:00Z"));
+}
+
+#[tokio::test]
+async fn test_update_api_key_unexpected_shape() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/account/keys/key_abc123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "key": { "id": "key_abc123", "name": "Staging" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .account()
+        .update_api_key("key_abc123", UpdateApiKeyRequest::new().name("Staging"))
+        .await;
+
+    assert!(matches!(result, Err(Error::Json(_))));
+}
+
+#[tokio::test]
+async fn test_update_api_key_not_found() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/account/keys/key_missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "API key not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .account()
+        .update_api_key("key_missing", UpdateApiKeyRequest::new().name("x"))
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound { .. })));
+}
+
+// ==================== usage() Tests ====================
+
+#[tokio::test]
+async fn test_usage_for_month() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/account/usage"))
+        .and(query_param("from", "2025-01-01T00:00:00Z"))
+        .and(query_param("to", "2025-01-31T23:59:59Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "usage": {
+                "messagesSent": 1200,
+                "creditsUsed": 1450,
+                "delivered": 1140,
+                "failed": 60,
+                "period": {
+                    "from": "2025-01-01T00:00:00Z",
+                    "to": "2025-01-31T23:59:59Z"
+                }
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let usage = client
+        .account()
+        .usage("2025-01-01T00:00:00Z", "2025-01-31T23:59:59Z")
+        .await
+        .unwrap();
+
+    assert_eq!(usage.messages_sent, 1200);
+    assert_eq!(usage.credits_used, 1450);
+    assert_eq!(usage.delivered, 1140);
+    assert_eq!(usage.failed, 60);
+    assert!((usage.delivery_rate() - 0.95).abs() < f64::EPSILON);
+    assert_eq!(usage.period.unwrap().from, "2025-01-01T00:00:00Z");
+}
+
+#[tokio::test]
+async fn test_usage_rejects_invalid_dates() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let empty = client.account().usage("", "2025-01-31").await;
+    assert!(matches!(empty, Err(Error::Validation { .. })));
+
+    let malformed = client.account().usage("2025-01-01", "last tuesday").await;
+    assert!(matches!(malformed, Err(Error::Validation { .. })));
+
+    for impossible in [
+        "2025-13-45",
+        "2025-02-29",
+        "2025-04-31",
+        "2025-01-01T24:00:00Z",
+    ] {
+        let result = client.account().usage("2025-01-01", impossible).await;
+        assert!(
+            matches!(result, Err(Error::Validation { .. })),
+            "{} should be rejected",
+            impossible
+        );
+    }
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_usage_accepts_unwrapped_body() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/account/usage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "messagesSent": 10,
+            "delivered": 9
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let usage = client
+        .account()
+        .usage("2024-02-01", "2024-02-29")
+        .await
+        .unwrap();
+
+    assert_eq!(usage.messages_sent, 10);
+    assert_eq!(usage.delivered, 9);
+}
+
+#[tokio::test]
+async fn test_usage_unexpected_shape() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/account/usage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "stats": { "sent": 10 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.account().usage("2025-01-01", "2025-01-31").await;
+
+    assert!(matches!(result, Err(Error::Json(_))));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use common::{mock_send_success, setup_mock_server, TEST_API_KEY};
+use sendly::blocking::Sendly;
+use sendly::SendlyConfig;
+
+// ==================== blocking send() Tests ====================
+
+#[test]
+fn test_blocking_send_success() {
+    // The mock server needs its own runtime; the blocking client brings one too.
+    let server_runtime = tokio::runtime::Runtime::new().unwrap();
+    let mock_server = server_runtime.block_on(async {
+        let mock_server = setup_mock_server().await;
+        mock_send_success().mount(&mock_server).await;
+        mock_server
+    });
+
+    let config = SendlyConfig::new()
+        .base_url(mock_server.uri())
+        .max_retries(0);
+    let client = Sendly::with_config(TEST_API_KEY, config);
+
+    let message = client
+        .messages()
+        .send_to("+15551234567", "Hello World")
+        .unwrap();
+
+    assert_eq!(message.id, "msg_abc123");
+}
+
+#[test]
+fn test_blocking_send_validation_error() {
+    let client = Sendly::new(TEST_API_KEY);
+
+    let result = client.messages().send_to("invalid-phone", "Hello");
+
+    assert!(matches!(result, Err(sendly::Error::Validation { .. })));
+}
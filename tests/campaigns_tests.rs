@@ -0,0 +1,164 @@
+mod common;
+
+use common::{create_test_client, setup_mock_server};
+use sendly::{CreateCampaignRequest, Error};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+// ==================== preview_recipients() Tests ====================
+
+#[tokio::test]
+async fn test_preview_recipients() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/campaigns/cmp_123/preview/recipients"))
+        .and(query_param("sample", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "recipients": [
+                {
+                    "phone": "+15551234567",
+                    "text": "Hi Alice, your order shipped!",
+                    "contactId": "cnt_1",
+                    "segments": 1
+                },
+                {
+                    "phone": "+15551234568",
+                    "text": "Hi Bob, your order shipped!",
+                    "contactId": "cnt_2",
+                    "segments": 1
+                },
+                {
+                    "phone": "+15551234569",
+                    "text": "Hi {{first_name}}, your order shipped!"
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let sample = client
+        .campaigns()
+        .preview_recipients("cmp_123", 3)
+        .await
+        .unwrap();
+
+    assert_eq!(sample.len(), 3);
+    assert_eq!(sample[0].phone, "+15551234567");
+    assert_eq!(sample[0].text, "Hi Alice, your order shipped!");
+    assert_eq!(sample[0].contact_id.as_deref(), Some("cnt_1"));
+    assert_eq!(sample[1].segments, Some(1));
+    assert!(sample[2].text.contains("{{first_name}}"));
+    assert!(sample[2].contact_id.is_none());
+}
+
+// ==================== create() Tests ====================
+
+#[tokio::test]
+async fn test_create_campaign() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/campaigns"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "cmp_123",
+            "name": "Launch",
+            "text": "We launched!",
+            "status": "draft"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let request = CreateCampaignRequest::new("Launch", "We launched!", vec!["lst_1".to_string()]);
+    let campaign = client.campaigns().create(request).await.unwrap();
+
+    assert_eq!(campaign.id, "cmp_123");
+}
+
+#[tokio::test]
+async fn test_create_campaign_without_contact_lists() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let request = CreateCampaignRequest::new("Launch", "We launched!", vec![]);
+    let result = client.campaigns().create(request).await;
+
+    match result {
+        Err(Error::Validation { message }) => assert!(message.contains("contact list")),
+        other => panic!("Expected Validation error, got: {:?}", other),
+    }
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_campaign_with_empty_text() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let request = CreateCampaignRequest::new("Launch", "", vec!["lst_1".to_string()]);
+    let result = client.campaigns().create(request).await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+#[tokio::test]
+async fn test_create_campaign_with_text_too_long() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let request = CreateCampaignRequest::new("Launch", "a".repeat(1601), vec!["lst_1".to_string()]);
+    let result = client.campaigns().create(request).await;
+
+    match result {
+        Err(Error::Validation { message }) => assert!(message.contains("exceeds maximum length")),
+        other => panic!("Expected Validation error, got: {:?}", other),
+    }
+}
+
+// ==================== send_throttled() Tests ====================
+
+#[tokio::test]
+async fn test_send_throttled() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/campaigns/cmp_123/send"))
+        .and(body_json(json!({"rate": 600})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "cmp_123",
+            "name": "Launch",
+            "text": "We launched!",
+            "status": "sending"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let campaign = client
+        .campaigns()
+        .send_throttled("cmp_123", 600)
+        .await
+        .unwrap();
+
+    assert_eq!(campaign.status, "sending");
+}
+
+#[tokio::test]
+async fn test_send_throttled_zero_rate() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.campaigns().send_throttled("cmp_123", 0).await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
@@ -0,0 +1,465 @@
+mod common;
+
+use common::{create_test_client, setup_mock_server};
+use sendly::{CreateContactRequest, Error, ListContactsOptions, UpdateContactRequest};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+// ==================== Tags Tests ====================
+
+#[tokio::test]
+async fn test_create_tagged_contact() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/contacts"))
+        .and(body_json(json!({
+            "phone_number": "+15551234567",
+            "name": "Alice",
+            "tags": ["vip", "beta"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "cnt_1",
+            "phoneNumber": "+15551234567",
+            "name": "Alice",
+            "tags": ["vip", "beta"]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let request = CreateContactRequest::new("+15551234567")
+        .name("Alice")
+        .tags(["vip"])
+        .add_tag("beta");
+    let contact = client.contacts().create(request).await.unwrap();
+
+    assert_eq!(contact.tags, vec!["vip", "beta"]);
+}
+
+#[test]
+fn test_contact_without_tags_defaults_to_empty() {
+    let contact: sendly::Contact = serde_json::from_value(json!({
+        "id": "cnt_1",
+        "phoneNumber": "+15551234567"
+    }))
+    .unwrap();
+
+    assert!(contact.tags.is_empty());
+    assert_eq!(
+        serde_json::to_value(CreateContactRequest::new("+15551234567")).unwrap(),
+        json!({"phone_number": "+15551234567"})
+    );
+}
+
+#[test]
+fn test_update_contact_clears_tags() {
+    assert_eq!(
+        serde_json::to_value(UpdateContactRequest::new().tags(Vec::<String>::new())).unwrap(),
+        json!({"tags": []})
+    );
+    assert_eq!(
+        serde_json::to_value(UpdateContactRequest::new().add_tag("vip")).unwrap(),
+        json!({"tags": ["vip"]})
+    );
+    assert_eq!(
+        serde_json::to_value(UpdateContactRequest::new()).unwrap(),
+        json!({})
+    );
+}
+
+#[tokio::test]
+async fn test_list_contacts_by_tags() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/contacts"))
+        .and(query_param("tags", "vip,beta"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": [{
+                "id": "cnt_1",
+                "phoneNumber": "+15551234567",
+                "tags": ["vip", "beta", "newsletter"]
+            }],
+            "total": 1
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListContactsOptions::new().tag("vip").tag("beta");
+    let result = client.contacts().list(options).await.unwrap();
+
+    assert_eq!(result.contacts.len(), 1);
+    assert!(result.contacts[0].tags.contains(&"vip".to_string()));
+}
+
+#[tokio::test]
+async fn test_create_contact_invalid_phone() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .contacts()
+        .create(CreateContactRequest::new("555-123-4567"))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+// ==================== Metadata Tests ====================
+
+#[tokio::test]
+async fn test_create_contact_too_many_metadata_keys() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/contacts"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let metadata = (0..=sendly::validate::MAX_METADATA_KEYS)
+        .map(|i| (format!("key_{}", i), json!(i)))
+        .collect();
+
+    let result = client
+        .contacts()
+        .create(CreateContactRequest::new("+15551234567").metadata(metadata))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+#[tokio::test]
+async fn test_update_contact_too_many_metadata_keys() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/contacts/cnt_1"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let metadata = (0..=sendly::validate::MAX_METADATA_KEYS)
+        .map(|i| (format!("key_{}", i), json!(i)))
+        .collect();
+
+    let result = client
+        .contacts()
+        .update("cnt_1", UpdateContactRequest::new().metadata(metadata))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+// ==================== upsert() Tests ====================
+
+#[tokio::test]
+async fn test_upsert_creates_new_contact() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/contacts"))
+        .and(query_param("search", "+15551234567"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": [{"id": "cnt_other", "phoneNumber": "+155512345670"}],
+            "total": 1
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/contacts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "cnt_new",
+            "phoneNumber": "+15551234567",
+            "name": "Alice"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .contacts()
+        .upsert(CreateContactRequest::new("+15551234567").name("Alice"))
+        .await
+        .unwrap();
+
+    assert!(result.was_created());
+    assert_eq!(result.contact().id, "cnt_new");
+}
+
+#[tokio::test]
+async fn test_upsert_updates_existing_contact() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/contacts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": [{"id": "cnt_1", "phoneNumber": "+15551234567", "name": "Al"}],
+            "total": 1
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/contacts/cnt_1"))
+        .and(body_json(json!({"name": "Alice", "tags": ["vip"]})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "cnt_1",
+            "phoneNumber": "+15551234567",
+            "name": "Alice",
+            "tags": ["vip"]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/contacts"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .contacts()
+        .upsert(
+            CreateContactRequest::new("+15551234567")
+                .name("Alice")
+                .add_tag("vip"),
+        )
+        .await
+        .unwrap();
+
+    assert!(!result.was_created());
+    assert_eq!(result.into_contact().name.as_deref(), Some("Alice"));
+}
+
+#[tokio::test]
+async fn test_upsert_finds_match_beyond_first_page() {
+    let mock_server = setup_mock_server().await;
+
+    // A full first page of fuzzy matches, none exact
+    let near_misses: Vec<_> = (0..100)
+        .map(|i| json!({"id": format!("cnt_{}", i), "phoneNumber": format!("+15551234567{}", i % 10)}))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/contacts"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": near_misses,
+            "total": 101
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/contacts"))
+        .and(query_param("offset", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": [{"id": "cnt_exact", "phoneNumber": "+15551234567"}],
+            "total": 101
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/contacts/cnt_exact"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "cnt_exact",
+            "phoneNumber": "+15551234567",
+            "name": "Alice"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/contacts"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .contacts()
+        .upsert(CreateContactRequest::new("+15551234567").name("Alice"))
+        .await
+        .unwrap();
+
+    assert!(!result.was_created());
+    assert_eq!(result.contact().id, "cnt_exact");
+}
+
+// ==================== move_contact() Tests ====================
+
+#[tokio::test]
+async fn test_move_contact() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/contact-lists/lst_b/contacts"))
+        .and(body_json(json!({"contact_ids": ["cnt_1"]})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/contact-lists/lst_a/contacts/cnt_1"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    client
+        .contacts()
+        .lists()
+        .move_contact("lst_a", "lst_b", "cnt_1")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_move_contact_remove_fails() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/contact-lists/lst_b/contacts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/contact-lists/lst_a/contacts/cnt_1"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "message": "Internal error"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .contacts()
+        .lists()
+        .move_contact("lst_a", "lst_b", "cnt_1")
+        .await;
+
+    match result {
+        Err(Error::PartialFailure { step, source }) => {
+            assert!(step.contains("Removing contact cnt_1 from list lst_a"));
+            assert!(matches!(*source, Error::ServerError { .. }));
+        }
+        other => panic!("Expected PartialFailure, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_move_contact_add_fails() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/contact-lists/lst_b/contacts"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "List not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .contacts()
+        .lists()
+        .move_contact("lst_a", "lst_b", "cnt_1")
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound { .. })));
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+}
+
+// ==================== export_csv() Tests ====================
+
+async fn mount_contact_pages(mock_server: &wiremock::MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/contacts"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": [
+                {"id": "cnt_1", "phoneNumber": "+15551234567", "name": "Alice", "email": "alice@example.com", "tags": ["vip", "beta"]},
+                {"id": "cnt_2", "phoneNumber": "+15551234568", "name": "Smith, Bob"}
+            ],
+            "total": 3
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/contacts"))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contacts": [
+                {"id": "cnt_3", "phoneNumber": "+15551234569", "name": "Carol \"CJ\""}
+            ],
+            "total": 3
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_export_csv() {
+    let mock_server = setup_mock_server().await;
+    mount_contact_pages(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let mut out = Vec::new();
+    let rows = client
+        .contacts()
+        .export_csv(&mut out, ListContactsOptions::new().limit(2))
+        .await
+        .unwrap();
+
+    assert_eq!(rows, 3);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "phone,name,email,tags\n\
+         +15551234567,Alice,alice@example.com,vip;beta\n\
+         +15551234568,\"Smith, Bob\",,\n\
+         +15551234569,\"Carol \"\"CJ\"\"\",,\n"
+    );
+}
+
+#[tokio::test]
+async fn test_export_csv_without_header() {
+    let mock_server = setup_mock_server().await;
+    mount_contact_pages(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let mut out = Vec::new();
+    client
+        .contacts()
+        .export_csv_with(&mut out, ListContactsOptions::new().limit(2), false)
+        .await
+        .unwrap();
+
+    let csv = String::from_utf8(out).unwrap();
+    assert!(csv.starts_with("+15551234567,"));
+    assert_eq!(csv.lines().count(), 3);
+}
@@ -24,12 +24,7 @@ async fn test_error_authentication() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -62,12 +57,7 @@ async fn test_error_authentication_with_message_field() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -99,12 +89,7 @@ async fn test_error_rate_limit_with_retry_after() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -144,12 +129,7 @@ async fn test_error_rate_limit_without_retry_after() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -183,12 +163,7 @@ async fn test_error_insufficient_credits() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -223,12 +198,7 @@ async fn test_error_validation_bad_request() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -264,12 +234,7 @@ async fn test_error_validation_unprocessable_entity() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -288,12 +253,7 @@ async fn test_error_validation_client_side_phone() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "invalid-phone".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("invalid-phone", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -312,12 +272,7 @@ async fn test_error_validation_client_side_text() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", ""))
         .await;
 
     assert!(result.is_err());
@@ -375,12 +330,7 @@ async fn test_error_network() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -414,7 +364,7 @@ async fn test_error_timeout() {
         .await;
 
     let config = sendly::SendlyConfig::new()
-        .base_url(&mock_server.uri())
+        .base_url(mock_server.uri())
         .timeout(std::time::Duration::from_millis(100))
         .max_retries(0);
 
@@ -422,12 +372,7 @@ async fn test_error_timeout() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -443,16 +388,72 @@ async fn test_error_timeout() {
     }
 }
 
+// ==================== Error::Forbidden / Error::Conflict Tests ====================
+
+#[tokio::test]
+async fn test_error_forbidden() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "error": "Key lacks messages:write scope"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Test"))
+        .await;
+
+    match result.unwrap_err() {
+        Error::Forbidden { message } => {
+            assert_eq!(message, "Key lacks messages:write scope");
+        }
+        _ => panic!("Expected Forbidden error"),
+    }
+}
+
+#[tokio::test]
+async fn test_error_conflict() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+            "error": "Webhook URL already registered"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Test"))
+        .await;
+
+    match result.unwrap_err() {
+        Error::Conflict { message } => {
+            assert_eq!(message, "Webhook URL already registered");
+        }
+        _ => panic!("Expected Conflict error"),
+    }
+}
+
 // ==================== Error::Api Tests ====================
 
 #[tokio::test]
-async fn test_error_api_500() {
+async fn test_error_api_unknown_status() {
     let mock_server = setup_mock_server().await;
 
     Mock::given(method("POST"))
         .and(path("/messages"))
-        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
-            "error": "Internal server error"
+        .respond_with(ResponseTemplate::new(418).set_body_json(json!({
+            "error": "I'm a teapot"
         })))
         .mount(&mock_server)
         .await;
@@ -461,12 +462,7 @@ async fn test_error_api_500() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -478,12 +474,12 @@ async fn test_error_api_500() {
             status_code,
             code,
         } => {
-            assert_eq!(message, "Internal server error");
-            assert_eq!(*status_code, 500);
+            assert_eq!(message, "I'm a teapot");
+            assert_eq!(*status_code, 418);
             assert_eq!(code, &None);
             assert!(!error.is_retryable());
             assert_eq!(error.retry_after(), None);
-            assert_eq!(error.to_string(), "API error (500): Internal server error");
+            assert_eq!(error.to_string(), "API error (418): I'm a teapot");
         }
         _ => panic!("Expected Api error"),
     }
@@ -495,9 +491,9 @@ async fn test_error_api_with_code() {
 
     Mock::given(method("POST"))
         .and(path("/messages"))
-        .respond_with(ResponseTemplate::new(503).set_body_json(json!({
-            "error": "Service temporarily unavailable",
-            "code": "SERVICE_UNAVAILABLE"
+        .respond_with(ResponseTemplate::new(410).set_body_json(json!({
+            "error": "Resource gone",
+            "code": "GONE"
         })))
         .mount(&mock_server)
         .await;
@@ -506,12 +502,7 @@ async fn test_error_api_with_code() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
@@ -521,16 +512,104 @@ async fn test_error_api_with_code() {
             status_code,
             code,
         } => {
-            assert_eq!(message, "Service temporarily unavailable");
-            assert_eq!(status_code, 503);
-            assert_eq!(code, Some("SERVICE_UNAVAILABLE".to_string()));
+            assert_eq!(message, "Resource gone");
+            assert_eq!(status_code, 410);
+            assert_eq!(code, Some("GONE".to_string()));
         }
         _ => panic!("Expected Api error"),
     }
 }
 
 #[tokio::test]
-async fn test_error_api_fallback_message() {
+async fn test_error_api_with_nested_error_object() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(410).set_body_json(json!({
+            "error": { "message": "Resource gone", "code": "GONE" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Test"))
+        .await;
+
+    match result.unwrap_err() {
+        Error::Api {
+            message,
+            status_code,
+            code,
+        } => {
+            assert_eq!(message, "Resource gone");
+            assert_eq!(status_code, 410);
+            assert_eq!(code, Some("GONE".to_string()));
+        }
+        other => panic!("Expected Api error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_error_validation_with_nested_error_object() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": { "message": "Invalid sender ID", "code": "invalid_sender" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Test"))
+        .await;
+
+    match result.unwrap_err() {
+        Error::Validation { message } => assert_eq!(message, "Invalid sender ID"),
+        other => panic!("Expected Validation error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_error_top_level_fields_win_over_nested_error() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(410).set_body_json(json!({
+            "message": "Top-level message",
+            "code": "TOP",
+            "error": { "message": "Nested message", "code": "NESTED" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Test"))
+        .await;
+
+    match result.unwrap_err() {
+        Error::Api { message, code, .. } => {
+            assert_eq!(message, "Top-level message");
+            assert_eq!(code, Some("TOP".to_string()));
+        }
+        other => panic!("Expected Api error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_error_server_error_fallback_message() {
     let mock_server = setup_mock_server().await;
 
     Mock::given(method("POST"))
@@ -543,25 +622,123 @@ async fn test_error_api_fallback_message() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Test".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Test"))
         .await;
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api {
+        Error::ServerError {
             message,
             status_code,
-            ..
         } => {
             assert_eq!(message, "Unknown error");
             assert_eq!(status_code, 502);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
+    }
+}
+
+#[tokio::test]
+async fn test_error_html_body_is_preserved() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/account"))
+        .respond_with(ResponseTemplate::new(502).set_body_string(
+            "<html><head><title>502 Bad Gateway</title></head><body>nginx</body></html>",
+        ))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    match client.ping().await.unwrap_err() {
+        Error::ServerError {
+            message,
+            status_code,
+        } => {
+            assert!(message.starts_with("Non-JSON response: "));
+            assert!(message.contains("502 Bad Gateway"));
+            assert_eq!(status_code, 502);
+        }
+        other => panic!("Expected ServerError, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_error_long_non_json_body_is_truncated() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/account"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("x".repeat(5000)))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let message = client.ping().await.unwrap_err().to_string();
+
+    assert!(message.ends_with("..."));
+    assert!(message.len() < 300);
+}
+
+#[tokio::test]
+async fn test_error_empty_body() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/account"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    match client.ping().await.unwrap_err() {
+        Error::ServerError { message, .. } => assert_eq!(message, "Empty response body"),
+        other => panic!("Expected ServerError, got: {:?}", other),
+    }
+}
+
+// ==================== Error::ServerError Tests ====================
+
+#[tokio::test]
+async fn test_error_server_error_500_and_503() {
+    for status in [500u16, 503] {
+        let mock_server = setup_mock_server().await;
+
+        Mock::given(method("POST"))
+            .and(path("/messages"))
+            .respond_with(ResponseTemplate::new(status).set_body_json(json!({
+                "error": "Internal server error"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(&mock_server.uri());
+
+        let error = client
+            .messages()
+            .send(SendMessageRequest::new("+15551234567", "Test"))
+            .await
+            .unwrap_err();
+
+        match &error {
+            Error::ServerError {
+                message,
+                status_code,
+            } => {
+                assert_eq!(message, "Internal server error");
+                assert_eq!(*status_code, status);
+            }
+            _ => panic!("Expected ServerError"),
+        }
+        assert!(error.is_retryable());
+        assert_eq!(
+            error.to_string(),
+            format!("Server error ({}): Internal server error", status)
+        );
     }
 }
 
@@ -580,6 +757,11 @@ async fn test_error_is_retryable() {
     }
     .is_retryable());
     assert!(Error::Timeout.is_retryable());
+    assert!(Error::ServerError {
+        message: "test".to_string(),
+        status_code: 503
+    }
+    .is_retryable());
 
     // Non-retryable errors
     assert!(!Error::Authentication {
@@ -606,6 +788,83 @@ async fn test_error_is_retryable() {
     .is_retryable());
 }
 
+#[tokio::test]
+async fn test_error_is_client_error_and_is_server_error() {
+    let message = || "test".to_string();
+
+    let client_errors = vec![
+        Error::Authentication { message: message() },
+        Error::Forbidden { message: message() },
+        Error::Validation { message: message() },
+        Error::NotFound { message: message() },
+        Error::InsufficientCredits { message: message() },
+        Error::Conflict { message: message() },
+        Error::Api {
+            message: message(),
+            status_code: 418,
+            code: None,
+        },
+    ];
+    for error in &client_errors {
+        assert!(
+            error.is_client_error(),
+            "{:?} should be a client error",
+            error
+        );
+        assert!(
+            !error.is_server_error(),
+            "{:?} is not a server error",
+            error
+        );
+    }
+
+    let server_errors = vec![
+        Error::ServerError {
+            message: message(),
+            status_code: 503,
+        },
+        Error::Api {
+            message: message(),
+            status_code: 500,
+            code: None,
+        },
+    ];
+    for error in &server_errors {
+        assert!(
+            error.is_server_error(),
+            "{:?} should be a server error",
+            error
+        );
+        assert!(
+            !error.is_client_error(),
+            "{:?} is not a client error",
+            error
+        );
+    }
+
+    let neither = vec![
+        Error::RateLimit {
+            message: message(),
+            retry_after: None,
+        },
+        Error::Network { message: message() },
+        Error::Timeout,
+        Error::Json(serde_json::from_str::<serde_json::Value>("{").unwrap_err()),
+    ];
+    for error in &neither {
+        assert!(
+            !error.is_client_error(),
+            "{:?} is not a client error",
+            error
+        );
+        assert!(
+            !error.is_server_error(),
+            "{:?} is not a server error",
+            error
+        );
+    }
+}
+
 #[tokio::test]
 async fn test_error_retry_after() {
     let rate_limit_with_retry = Error::RateLimit {
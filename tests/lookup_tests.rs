@@ -0,0 +1,91 @@
+mod common;
+
+use common::{create_test_client, setup_mock_server};
+use sendly::{Error, LineType, NumberLookup};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+// ==================== Model Tests ====================
+
+#[test]
+fn test_number_lookup_deserialize_mobile() {
+    let lookup: NumberLookup = serde_json::from_value(json!({
+        "phone": "+15551234567",
+        "lineType": "mobile",
+        "carrierName": "T-Mobile USA",
+        "country": "US",
+        "isValid": true
+    }))
+    .unwrap();
+
+    assert_eq!(lookup.line_type, LineType::Mobile);
+    assert!(lookup.is_mobile());
+    assert_eq!(lookup.carrier_name.as_deref(), Some("T-Mobile USA"));
+    assert_eq!(lookup.country.as_deref(), Some("US"));
+    assert!(lookup.is_valid);
+}
+
+#[test]
+fn test_number_lookup_deserialize_landline() {
+    let lookup: NumberLookup = serde_json::from_value(json!({
+        "phone": "+442071234567",
+        "lineType": "landline",
+        "carrierName": "BT",
+        "country": "GB",
+        "isValid": true
+    }))
+    .unwrap();
+
+    assert!(lookup.is_landline());
+    assert!(!lookup.is_mobile());
+}
+
+#[test]
+fn test_number_lookup_unknown_line_type() {
+    let lookup: NumberLookup = serde_json::from_value(json!({
+        "phone": "+15551234567",
+        "lineType": "pager"
+    }))
+    .unwrap();
+
+    assert_eq!(lookup.line_type, LineType::Unknown);
+    assert!(!lookup.is_valid);
+}
+
+// ==================== number() Tests ====================
+
+#[tokio::test]
+async fn test_lookup_number() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/lookup/%2B15551234567"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "phone": "+15551234567",
+            "lineType": "mobile",
+            "carrierName": "Verizon Wireless",
+            "country": "US",
+            "isValid": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let lookup = client.lookup().number("+15551234567").await.unwrap();
+
+    assert!(lookup.is_mobile());
+    assert_eq!(lookup.carrier_name.as_deref(), Some("Verizon Wireless"));
+}
+
+#[tokio::test]
+async fn test_lookup_number_invalid_phone() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.lookup().number("not-a-number").await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
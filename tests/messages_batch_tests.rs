@@ -2,9 +2,12 @@ mod common;
 
 use common::{create_test_client, mock_batch_send_success, setup_mock_server};
 use common::{mock_get_batch_success, mock_list_batches_success};
-use sendly::{BatchMessageItem, BatchStatus, Error, ListBatchesOptions, SendBatchRequest};
+use sendly::{
+    BatchMessageItem, BatchStatus, Error, ListBatchesOptions, SendBatchRequest, SortOrder,
+};
 use serde_json::json;
-use wiremock::matchers::{method, path, path_regex, query_param};
+use std::collections::HashMap;
+use wiremock::matchers::{body_json, method, path, path_regex, query_param};
 use wiremock::{Mock, ResponseTemplate};
 
 // ==================== send_batch() Tests ====================
@@ -23,17 +26,20 @@ async fn test_send_batch_success() {
                 BatchMessageItem {
                     to: "+15551111111".to_string(),
                     text: "Message 1".to_string(),
+                    variables: None,
                     metadata: None,
                 },
                 BatchMessageItem {
                     to: "+15552222222".to_string(),
                     text: "Message 2".to_string(),
+                    variables: None,
                     metadata: None,
                 },
             ],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -57,6 +63,7 @@ async fn test_send_batch_empty_messages() {
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -81,17 +88,20 @@ async fn test_send_batch_invalid_phone() {
                 BatchMessageItem {
                     to: "+15551111111".to_string(),
                     text: "Valid".to_string(),
+                    variables: None,
                     metadata: None,
                 },
                 BatchMessageItem {
                     to: "invalid-phone".to_string(),
                     text: "Invalid".to_string(),
+                    variables: None,
                     metadata: None,
                 },
             ],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -104,6 +114,36 @@ async fn test_send_batch_invalid_phone() {
     }
 }
 
+#[tokio::test]
+async fn test_send_batch_item_metadata_too_large() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let metadata = (0..=sendly::validate::MAX_METADATA_KEYS)
+        .map(|i| (format!("key_{}", i), json!(i)))
+        .collect();
+
+    let result = client
+        .messages()
+        .send_batch(SendBatchRequest {
+            messages: vec![
+                BatchMessageItem::new("+15551111111", "Valid"),
+                BatchMessageItem::new("+15552222222", "Valid").metadata(metadata),
+            ],
+            from: None,
+            message_type: None,
+            metadata: None,
+            template_id: None,
+        })
+        .await;
+
+    match result {
+        Err(Error::Validation { message }) => assert!(message.contains("at index 1")),
+        other => panic!("Expected Validation error, got {:?}", other),
+    }
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn test_send_batch_invalid_text() {
     let mock_server = setup_mock_server().await;
@@ -116,17 +156,20 @@ async fn test_send_batch_invalid_text() {
                 BatchMessageItem {
                     to: "+15551111111".to_string(),
                     text: "Valid".to_string(),
+                    variables: None,
                     metadata: None,
                 },
                 BatchMessageItem {
                     to: "+15552222222".to_string(),
                     text: "".to_string(),
+                    variables: None,
                     metadata: None,
                 },
             ],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -152,11 +195,13 @@ async fn test_send_batch_text_too_long() {
             messages: vec![BatchMessageItem {
                 to: "+15551111111".to_string(),
                 text: long_text,
+                variables: None,
                 metadata: None,
             }],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -189,11 +234,13 @@ async fn test_send_batch_authentication_error() {
             messages: vec![BatchMessageItem {
                 to: "+15551111111".to_string(),
                 text: "Test".to_string(),
+                variables: None,
                 metadata: None,
             }],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -221,11 +268,13 @@ async fn test_send_batch_insufficient_credits() {
             messages: vec![BatchMessageItem {
                 to: "+15551111111".to_string(),
                 text: "Test".to_string(),
+                variables: None,
                 metadata: None,
             }],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -256,11 +305,13 @@ async fn test_send_batch_not_found() {
             messages: vec![BatchMessageItem {
                 to: "+15551111111".to_string(),
                 text: "Test".to_string(),
+                variables: None,
                 metadata: None,
             }],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -290,11 +341,13 @@ async fn test_send_batch_rate_limit() {
             messages: vec![BatchMessageItem {
                 to: "+15551111111".to_string(),
                 text: "Test".to_string(),
+                variables: None,
                 metadata: None,
             }],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
@@ -327,23 +380,138 @@ async fn test_send_batch_server_error() {
             messages: vec![BatchMessageItem {
                 to: "+15551111111".to_string(),
                 text: "Test".to_string(),
+                variables: None,
                 metadata: None,
             }],
             from: None,
             message_type: None,
             metadata: None,
+            template_id: None,
         })
         .await;
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
+    }
+}
+
+#[test]
+fn test_templated_batch_serialization() {
+    let request = SendBatchRequest::templated(
+        "tpl_welcome",
+        vec![BatchMessageItem::templated(
+            "+15551111111",
+            HashMap::from([("name".to_string(), "Alice".to_string())]),
+        )],
+    );
+
+    let body = serde_json::to_value(&request).unwrap();
+
+    assert_eq!(
+        body,
+        json!({
+            "messages": [{ "to": "+15551111111", "variables": { "name": "Alice" } }],
+            "templateId": "tpl_welcome"
+        })
+    );
+}
+
+#[test]
+fn test_plain_batch_serialization_omits_template_fields() {
+    let request = SendBatchRequest::new(vec![BatchMessageItem::new("+15551111111", "Hello")]);
+
+    let body = serde_json::to_value(&request).unwrap();
+
+    assert_eq!(
+        body,
+        json!({ "messages": [{ "to": "+15551111111", "text": "Hello" }] })
+    );
+}
+
+#[tokio::test]
+async fn test_send_batch_templated() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/batch"))
+        .and(body_json(json!({
+            "messages": [
+                { "to": "+15551111111", "variables": { "name": "Alice" } },
+                { "to": "+15552222222", "variables": { "name": "Bob" } }
+            ],
+            "templateId": "tpl_welcome"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "batchId": "batch_abc123",
+            "status": "processing",
+            "total": 2,
+            "queued": 2,
+            "sent": 0,
+            "failed": 0,
+            "creditsUsed": 2,
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let vars = |name: &str| HashMap::from([("name".to_string(), name.to_string())]);
+
+    let batch = client
+        .messages()
+        .send_batch(SendBatchRequest::templated(
+            "tpl_welcome",
+            vec![
+                BatchMessageItem::templated("+15551111111", vars("Alice")),
+                BatchMessageItem::templated("+15552222222", vars("Bob")),
+            ],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(batch.batch_id, "batch_abc123");
+}
+
+#[tokio::test]
+async fn test_send_batch_variables_without_template() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send_batch(SendBatchRequest::new(vec![BatchMessageItem::templated(
+            "+15551111111",
+            HashMap::new(),
+        )]))
+        .await;
+
+    match result.unwrap_err() {
+        Error::Validation { message } => assert!(message.contains("Template ID")),
+        other => panic!("Expected Validation error, got {:?}", other),
     }
 }
 
+#[tokio::test]
+async fn test_send_batch_item_without_text_or_variables() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send_batch(SendBatchRequest::templated(
+            "tpl_welcome",
+            vec![BatchMessageItem::new("+15551111111", "")],
+        ))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
 // ==================== get_batch() Tests ====================
 
 #[tokio::test]
@@ -470,13 +638,65 @@ async fn test_get_batch_server_error() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
     }
 }
 
+// ==================== batch_exists() Tests ====================
+
+#[tokio::test]
+async fn test_batch_exists_found() {
+    let mock_server = setup_mock_server().await;
+    mock_get_batch_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().batch_exists("batch_abc123").await;
+
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn test_batch_exists_not_found() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/batch/batch_missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Batch not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().batch_exists("batch_missing").await;
+
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn test_batch_exists_propagates_other_errors() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/batch/batch_missing"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Invalid API key"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().batch_exists("batch_missing").await;
+
+    assert!(matches!(result, Err(Error::Authentication { .. })));
+}
+
 // ==================== list_batches() Tests ====================
 
 #[tokio::test]
@@ -523,6 +743,30 @@ async fn test_list_batches_with_options() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_list_batches_sort_asc() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/batches"))
+        .and(query_param("sort", "createdAt:asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListBatchesOptions::new().sort(SortOrder::Asc);
+
+    let result = client.messages().list_batches(Some(options)).await;
+
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn test_list_batches_authentication_error() {
     let mock_server = setup_mock_server().await;
@@ -608,9 +852,9 @@ async fn test_list_batches_server_error() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
     }
 }
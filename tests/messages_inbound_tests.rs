@@ -0,0 +1,167 @@
+mod common;
+
+use common::{create_test_client, setup_mock_server};
+use futures::StreamExt;
+use sendly::{Error, InboundMessage, InboundMessageList, ListInboundMessagesOptions};
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+fn inbound_json(id: &str, from: &str, text: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "from": from,
+        "to": "+15550001111",
+        "text": text,
+        "receivedAt": "2025-01-15T10:00:00Z"
+    })
+}
+
+// ==================== Model Tests ====================
+
+#[test]
+fn test_inbound_message_list_deserialize() {
+    let page: InboundMessageList = serde_json::from_value(json!({
+        "data": [
+            inbound_json("in_1", "+15551234567", "STOP"),
+            inbound_json("in_2", "+15559876543", "Yes please"),
+        ],
+        "count": 2
+    }))
+    .unwrap();
+
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.total(), 2);
+
+    let messages: Vec<InboundMessage> = page.into_iter().collect();
+    assert_eq!(messages[0].id, "in_1");
+    assert_eq!(messages[0].from, "+15551234567");
+    assert_eq!(messages[0].to, "+15550001111");
+    assert_eq!(messages[0].text, "STOP");
+    assert_eq!(
+        messages[0].received_at.as_deref(),
+        Some("2025-01-15T10:00:00Z")
+    );
+}
+
+// ==================== list_inbound() Tests ====================
+
+#[tokio::test]
+async fn test_list_inbound_success() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/inbound"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [inbound_json("in_1", "+15551234567", "STOP")],
+            "count": 1
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let page = client.messages().list_inbound(None).await.unwrap();
+
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.data[0].text, "STOP");
+}
+
+#[tokio::test]
+async fn test_list_inbound_with_filters() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/inbound"))
+        .and(query_param("from", "+15551234567"))
+        .and(query_param("receivedAfter", "2025-01-15T00:00:00Z"))
+        .and(query_param("receivedBefore", "2025-01-16T00:00:00Z"))
+        .and(query_param("limit", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListInboundMessagesOptions::new()
+        .limit(10)
+        .from("+15551234567")
+        .received_after("2025-01-15T00:00:00Z")
+        .received_before("2025-01-16T00:00:00Z");
+
+    let result = client.messages().list_inbound(Some(options)).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_list_inbound_authentication_error() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/inbound"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Invalid API key"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().list_inbound(None).await;
+
+    assert!(matches!(result, Err(Error::Authentication { .. })));
+}
+
+// ==================== iter_inbound() Tests ====================
+
+#[tokio::test]
+async fn test_iter_inbound_paginates() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/inbound"))
+        .and(query_param("offset", "0"))
+        .and(query_param("from", "+15551234567"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [
+                inbound_json("in_1", "+15551234567", "Hi"),
+                inbound_json("in_2", "+15551234567", "Hello"),
+            ],
+            "count": 3
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/inbound"))
+        .and(query_param("offset", "2"))
+        .and(query_param("from", "+15551234567"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [inbound_json("in_3", "+15551234567", "STOP")],
+            "count": 3
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let options = ListInboundMessagesOptions::new()
+        .limit(2)
+        .from("+15551234567");
+    let ids: Vec<String> = messages
+        .iter_inbound(Some(options))
+        .map(|r| r.unwrap().id)
+        .collect()
+        .await;
+
+    assert_eq!(ids, vec!["in_1", "in_2", "in_3"]);
+}
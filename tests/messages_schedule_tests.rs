@@ -4,9 +4,12 @@ use common::{
     create_test_client, mock_list_scheduled_success, mock_schedule_success, setup_mock_server,
 };
 use common::{mock_cancel_scheduled_success, mock_get_scheduled_success};
-use sendly::{Error, ListScheduledMessagesOptions, ScheduleMessageRequest, ScheduledMessageStatus};
+use sendly::{
+    Error, ListScheduledMessagesOptions, MessageType, ScheduleMessageRequest,
+    ScheduledMessageStatus, UpdateScheduledRequest,
+};
 use serde_json::json;
-use wiremock::matchers::{method, path, path_regex, query_param};
+use wiremock::matchers::{body_json, method, path, path_regex, query_param};
 use wiremock::{Mock, ResponseTemplate};
 
 // ==================== schedule() Tests ====================
@@ -39,6 +42,85 @@ async fn test_schedule_success() {
     assert_eq!(scheduled.credits_reserved, 1);
 }
 
+#[tokio::test]
+async fn test_schedule_with_builder() {
+    let mock_server = setup_mock_server().await;
+    mock_schedule_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let request =
+        ScheduleMessageRequest::new("+15551234567", "Scheduled message", "2025-01-20T10:00:00Z")
+            .from("ACME")
+            .message_type(MessageType::Transactional);
+
+    assert_eq!(request.from.as_deref(), Some("ACME"));
+    assert_eq!(request.message_type, Some(MessageType::Transactional));
+    assert!(request.metadata.is_none());
+
+    client.messages().schedule(request).await.unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "to": "+15551234567",
+            "text": "Scheduled message",
+            "scheduledAt": "2025-01-20T10:00:00Z",
+            "from": "ACME",
+            "messageType": "transactional"
+        })
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_schedule_in() {
+    let mock_server = setup_mock_server().await;
+    mock_schedule_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let expected = chrono::Utc::now() + chrono::Duration::minutes(30);
+    client
+        .messages()
+        .schedule_in("+15551234567", "Reminder", chrono::Duration::minutes(30))
+        .await
+        .unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    let scheduled_at =
+        chrono::DateTime::parse_from_rfc3339(body["scheduledAt"].as_str().unwrap()).unwrap();
+
+    let drift = (scheduled_at.with_timezone(&chrono::Utc) - expected).num_seconds();
+    assert!(drift.abs() <= 5, "scheduledAt off by {}s", drift);
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_schedule_at_rejects_past_time() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let past = chrono::Utc::now() - chrono::Duration::minutes(1);
+    let result = client
+        .messages()
+        .schedule_at("+15551234567", "Reminder", past)
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+
+    let result = client
+        .messages()
+        .schedule_in("+15551234567", "Reminder", chrono::Duration::zero())
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn test_schedule_invalid_phone() {
     let mock_server = setup_mock_server().await;
@@ -273,10 +355,35 @@ async fn test_schedule_server_error() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
+    }
+}
+
+#[tokio::test]
+async fn test_schedule_metadata_value_too_long() {
+    let mock_server = setup_mock_server().await;
+    mock_schedule_success().expect(0).mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+    let metadata = std::collections::HashMap::from([(
+        "campaign".to_string(),
+        json!("x".repeat(sendly::validate::MAX_METADATA_VALUE_LENGTH + 1)),
+    )]);
+
+    let result = client
+        .messages()
+        .schedule(
+            ScheduleMessageRequest::new("+15551234567", "Hello", "2025-01-20T10:00:00Z")
+                .metadata(metadata),
+        )
+        .await;
+
+    match result.unwrap_err() {
+        Error::Validation { message } => assert!(message.contains("'campaign'")),
+        other => panic!("Expected Validation error, got {:?}", other),
     }
 }
 
@@ -326,6 +433,185 @@ async fn test_list_scheduled_with_options() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_list_scheduled_to_filter() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled"))
+        .and(query_param("to", "+15551234567"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListScheduledMessagesOptions::new().to("+15551234567");
+    let result = client.messages().list_scheduled(Some(options)).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_list_scheduled_invalid_to() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListScheduledMessagesOptions::new().to("5551234567");
+    let result = client.messages().list_scheduled(Some(options)).await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_iter_scheduled_keeps_to_filter() {
+    use futures::StreamExt;
+
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled"))
+        .and(query_param("to", "+15551234567"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{
+                "id": "sched_1",
+                "to": "+15551234567",
+                "text": "Reminder",
+                "scheduledAt": "2025-01-20T10:00:00Z",
+                "status": "scheduled"
+            }],
+            "count": 1
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let scheduled: Vec<_> = messages
+        .iter_scheduled(Some(ListScheduledMessagesOptions::new().to("+15551234567")))
+        .collect()
+        .await;
+
+    assert_eq!(scheduled.len(), 1);
+    assert_eq!(scheduled[0].as_ref().unwrap().id, "sched_1");
+}
+
+#[tokio::test]
+async fn test_list_scheduled_date_window() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled"))
+        .and(query_param("scheduledAfter", "2025-01-20T00:00:00Z"))
+        .and(query_param("scheduledBefore", "2025-01-27T00:00:00Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListScheduledMessagesOptions::new()
+        .scheduled_after("2025-01-20T00:00:00Z")
+        .scheduled_before("2025-01-27T00:00:00Z");
+    let result = client.messages().list_scheduled(Some(options)).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_list_scheduled_inverted_window() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListScheduledMessagesOptions::new()
+        .scheduled_after("2025-01-27T00:00:00Z")
+        .scheduled_before("2025-01-20T00:00:00Z");
+    let result = client.messages().list_scheduled(Some(options)).await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_list_scheduled_window_mixed_offsets_and_precision() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    // 10:00+05:00 is 05:00Z, before 06:00Z
+    let options = ListScheduledMessagesOptions::new()
+        .scheduled_after("2025-01-20T10:00:00+05:00")
+        .scheduled_before("2025-01-20T06:00:00Z");
+    assert!(client
+        .messages()
+        .list_scheduled(Some(options))
+        .await
+        .is_ok());
+
+    let options = ListScheduledMessagesOptions::new()
+        .scheduled_after("2025-01-20T10:00:00Z")
+        .scheduled_before("2025-01-20T10:00:00.5Z");
+    assert!(client
+        .messages()
+        .list_scheduled(Some(options))
+        .await
+        .is_ok());
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_iter_scheduled_keeps_date_window() {
+    use chrono::TimeZone;
+    use futures::StreamExt;
+
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled"))
+        .and(query_param("scheduledAfter", "2025-01-20T00:00:00+00:00"))
+        .and(query_param("scheduledBefore", "2025-01-27T00:00:00+00:00"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let options = ListScheduledMessagesOptions::new()
+        .scheduled_after_datetime(chrono::Utc.with_ymd_and_hms(2025, 1, 20, 0, 0, 0).unwrap())
+        .scheduled_before_datetime(chrono::Utc.with_ymd_and_hms(2025, 1, 27, 0, 0, 0).unwrap());
+    let scheduled: Vec<_> = messages.iter_scheduled(Some(options)).collect().await;
+
+    assert!(scheduled.is_empty());
+}
+
 #[tokio::test]
 async fn test_list_scheduled_authentication_error() {
     let mock_server = setup_mock_server().await;
@@ -411,10 +697,10 @@ async fn test_list_scheduled_server_error() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
     }
 }
 
@@ -541,13 +827,244 @@ async fn test_get_scheduled_server_error() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
     }
 }
 
+// ==================== scheduled_exists() Tests ====================
+
+#[tokio::test]
+async fn test_scheduled_exists_found() {
+    let mock_server = setup_mock_server().await;
+    mock_get_scheduled_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().scheduled_exists("sched_abc123").await;
+
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn test_scheduled_exists_not_found() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled/sched_missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Scheduled message not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().scheduled_exists("sched_missing").await;
+
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn test_scheduled_exists_propagates_other_errors() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled/sched_missing"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Invalid API key"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().scheduled_exists("sched_missing").await;
+
+    assert!(matches!(result, Err(Error::Authentication { .. })));
+}
+
+// ==================== update_scheduled() Tests ====================
+
+fn updated_scheduled(text: &str, scheduled_at: &str) -> serde_json::Value {
+    json!({
+        "id": "sched_abc123",
+        "to": "+15551234567",
+        "text": text,
+        "scheduledAt": scheduled_at,
+        "status": "scheduled",
+        "creditsReserved": 1,
+        "createdAt": "2025-01-15T10:00:00Z"
+    })
+}
+
+#[tokio::test]
+async fn test_update_scheduled_text_only() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/messages/scheduled/sched_abc123"))
+        .and(body_json(json!({"text": "Fixed typo"})))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(updated_scheduled("Fixed typo", "2099-01-20T10:00:00Z")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let scheduled = client
+        .messages()
+        .update_scheduled(
+            "sched_abc123",
+            UpdateScheduledRequest::new().text("Fixed typo"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(scheduled.text, "Fixed typo");
+}
+
+#[tokio::test]
+async fn test_update_scheduled_time_only() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/messages/scheduled/sched_abc123"))
+        .and(body_json(json!({"scheduledAt": "2099-01-21T09:00:00Z"})))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(updated_scheduled("Reminder", "2099-01-21T09:00:00Z")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let scheduled = client
+        .messages()
+        .update_scheduled(
+            "sched_abc123",
+            UpdateScheduledRequest::new().scheduled_at("2099-01-21T09:00:00Z"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(scheduled.scheduled_at, "2099-01-21T09:00:00Z");
+}
+
+#[tokio::test]
+async fn test_update_scheduled_validation() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let empty = messages
+        .update_scheduled("sched_abc123", UpdateScheduledRequest::new())
+        .await;
+    assert!(matches!(empty, Err(Error::Validation { .. })));
+
+    let too_long = messages
+        .update_scheduled(
+            "sched_abc123",
+            UpdateScheduledRequest::new().text("a".repeat(1601)),
+        )
+        .await;
+    assert!(matches!(too_long, Err(Error::Validation { .. })));
+
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_update_scheduled_rejects_past_time() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .update_scheduled(
+            "sched_abc123",
+            UpdateScheduledRequest::new().scheduled_at("2020-01-01T00:00:00Z"),
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+// ==================== preview_cancel_scheduled() Tests ====================
+
+#[tokio::test]
+async fn test_preview_cancel_scheduled_returns_reserved_credits() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled/sched_abc123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "sched_abc123",
+            "to": "+15551234567",
+            "text": "Scheduled message",
+            "scheduledAt": "2025-01-20T10:00:00Z",
+            "status": "scheduled",
+            "creditsReserved": 3,
+            "createdAt": "2025-01-15T10:00:00Z"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Previewing must never cancel
+    mock_cancel_scheduled_success()
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let refund = client
+        .messages()
+        .preview_cancel_scheduled("sched_abc123")
+        .await
+        .unwrap();
+
+    assert_eq!(refund, 3);
+}
+
+#[tokio::test]
+async fn test_preview_cancel_scheduled_already_sent() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/scheduled/sched_abc123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "sched_abc123",
+            "to": "+15551234567",
+            "text": "Scheduled message",
+            "scheduledAt": "2025-01-20T10:00:00Z",
+            "status": "sent",
+            "creditsReserved": 3,
+            "createdAt": "2025-01-15T10:00:00Z"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let refund = client
+        .messages()
+        .preview_cancel_scheduled("sched_abc123")
+        .await
+        .unwrap();
+
+    assert_eq!(refund, 0);
+}
+
 // ==================== cancel_scheduled() Tests ====================
 
 #[tokio::test]
@@ -675,9 +1192,9 @@ async fn test_cancel_scheduled_server_error() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
     }
 }
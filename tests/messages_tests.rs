@@ -7,7 +7,10 @@ use common::{
     mock_auth_error, mock_insufficient_credits, mock_not_found, mock_rate_limit, mock_server_error,
 };
 use futures::StreamExt;
-use sendly::{Error, ListMessagesOptions, MessageStatus, SendMessageRequest};
+use sendly::{
+    CancellationToken, Channel, Error, ListMessagesOptions, MessageStatus, SendMessageRequest,
+    SortOrder,
+};
 use serde_json::json;
 use wiremock::matchers::{method, path, query_param};
 use wiremock::{Mock, ResponseTemplate};
@@ -23,12 +26,7 @@ async fn test_send_success() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Hello World".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Hello World"))
         .await;
 
     assert!(result.is_ok());
@@ -48,12 +46,7 @@ async fn test_send_invalid_phone_format() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "invalid-phone".to_string(),
-            text: "Hello".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("invalid-phone", "Hello"))
         .await;
 
     assert!(result.is_err());
@@ -72,12 +65,7 @@ async fn test_send_empty_text() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", ""))
         .await;
 
     assert!(result.is_err());
@@ -98,12 +86,7 @@ async fn test_send_text_too_long() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: long_text,
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", long_text))
         .await;
 
     assert!(result.is_err());
@@ -115,6 +98,89 @@ async fn test_send_text_too_long() {
     }
 }
 
+#[tokio::test]
+async fn test_send_with_channel() {
+    let mock_server = setup_mock_server().await;
+    mock_send_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    client
+        .messages()
+        .send(
+            SendMessageRequest::new("+15551234567", "Your order has shipped")
+                .channel(Channel::Whatsapp)
+                .fallback_channel(Channel::Sms),
+        )
+        .await
+        .unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["channel"], "whatsapp");
+    assert_eq!(body["fallbackChannel"], "sms");
+}
+
+#[tokio::test]
+async fn test_send_without_channel_omits_it() {
+    let mock_server = setup_mock_server().await;
+    mock_send_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    client
+        .messages()
+        .send_to("+15551234567", "Hello")
+        .await
+        .unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert!(body.get("channel").is_none());
+    assert!(body.get("fallbackChannel").is_none());
+}
+
+#[tokio::test]
+async fn test_send_whatsapp_skips_sms_length_limit() {
+    let mock_server = setup_mock_server().await;
+    mock_send_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "a".repeat(1601)).channel(Channel::Whatsapp))
+        .await;
+
+    assert!(result.is_ok());
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "").channel(Channel::Whatsapp))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+#[tokio::test]
+async fn test_send_whatsapp_with_sms_fallback_checks_sms_length() {
+    let mock_server = setup_mock_server().await;
+    mock_send_success().expect(0).mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send(
+            SendMessageRequest::new("+15551234567", "a".repeat(5000))
+                .channel(Channel::Whatsapp)
+                .fallback_channel(Channel::Sms),
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
 #[tokio::test]
 async fn test_send_authentication_error() {
     let mock_server = setup_mock_server().await;
@@ -124,12 +190,7 @@ async fn test_send_authentication_error() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Hello".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Hello"))
         .await;
 
     assert!(result.is_err());
@@ -150,12 +211,7 @@ async fn test_send_insufficient_credits() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Hello".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Hello"))
         .await;
 
     assert!(result.is_err());
@@ -176,12 +232,7 @@ async fn test_send_rate_limit() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Hello".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Hello"))
         .await;
 
     assert!(result.is_err());
@@ -206,20 +257,15 @@ async fn test_send_server_error() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Hello".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Hello"))
         .await;
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
     }
 }
 
@@ -235,12 +281,7 @@ async fn test_send_network_error() {
 
     let result = client
         .messages()
-        .send(SendMessageRequest {
-            to: "+15551234567".to_string(),
-            text: "Hello".to_string(),
-            message_type: None,
-            metadata: None,
-        })
+        .send(SendMessageRequest::new("+15551234567", "Hello"))
         .await;
 
     assert!(result.is_err());
@@ -251,6 +292,119 @@ async fn test_send_network_error() {
     ));
 }
 
+#[tokio::test]
+async fn test_send_metadata_too_many_keys() {
+    let mock_server = setup_mock_server().await;
+    mock_send_success().expect(0).mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+    let metadata = (0..=sendly::validate::MAX_METADATA_KEYS)
+        .map(|i| (format!("key_{}", i), json!(i)))
+        .collect();
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Hello").metadata(metadata))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+#[tokio::test]
+async fn test_send_metadata_value_too_long() {
+    let mock_server = setup_mock_server().await;
+    mock_send_success().expect(0).mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+    let metadata = std::collections::HashMap::from([(
+        "note".to_string(),
+        json!("x".repeat(sendly::validate::MAX_METADATA_VALUE_LENGTH + 1)),
+    )]);
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Hello").metadata(metadata))
+        .await;
+
+    match result.unwrap_err() {
+        Error::Validation { message } => assert!(message.contains("'note'")),
+        other => panic!("Expected Validation error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_send_metadata_uses_configured_limits() {
+    let mock_server = setup_mock_server().await;
+    mock_send_success().expect(0).mount(&mock_server).await;
+
+    let config = sendly::SendlyConfig::new()
+        .base_url(mock_server.uri())
+        .max_retries(0)
+        .metadata_limits(sendly::validate::MetadataLimits {
+            max_keys: 1,
+            ..Default::default()
+        });
+    let client = sendly::Sendly::with_config(common::TEST_API_KEY, config);
+    let metadata =
+        std::collections::HashMap::from([("a".to_string(), json!(1)), ("b".to_string(), json!(2))]);
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Hello").metadata(metadata))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+// ==================== send_cancellable() Tests ====================
+
+#[tokio::test]
+async fn test_send_cancellable_success() {
+    let mock_server = setup_mock_server().await;
+    mock_send_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let message = client
+        .messages()
+        .send_cancellable(
+            SendMessageRequest::new("+15551234567", "Hello World"),
+            CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(message.id, "msg_abc123");
+}
+
+#[tokio::test]
+async fn test_send_cancellable_cancelled_during_retry_sleep() {
+    // Nothing listens on port 1, so each attempt fails to connect and the
+    // client backs off for a second before retrying
+    let config = sendly::SendlyConfig::new()
+        .base_url("http://127.0.0.1:1")
+        .timeout(std::time::Duration::from_secs(1))
+        .max_retries(3);
+
+    let client = sendly::Sendly::with_config("test_key", config);
+
+    let cancel = CancellationToken::new();
+    let trigger = cancel.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        trigger.cancel();
+    });
+
+    let started = std::time::Instant::now();
+    let result = client
+        .messages()
+        .send_cancellable(SendMessageRequest::new("+15551234567", "Hello"), cancel)
+        .await;
+
+    assert!(matches!(result, Err(Error::Cancelled)));
+    assert!(started.elapsed() < std::time::Duration::from_millis(900));
+}
+
 // ==================== send_to() Tests ====================
 
 #[tokio::test]
@@ -278,6 +432,53 @@ async fn test_send_to_invalid_phone() {
     assert!(matches!(result.unwrap_err(), Error::Validation { .. }));
 }
 
+// ==================== preview() Tests ====================
+
+#[tokio::test]
+async fn test_preview_success() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/preview"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "canSend": true,
+            "segments": 2,
+            "creditsNeeded": 2,
+            "encoding": "GSM-7",
+            "currentBalance": 100
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let preview = client
+        .messages()
+        .preview(SendMessageRequest::new("+15551234567", "a".repeat(200)))
+        .await
+        .unwrap();
+
+    assert!(preview.can_send);
+    assert_eq!(preview.segments, 2);
+    assert_eq!(preview.credits_needed, 2);
+    assert_eq!(preview.encoding.as_deref(), Some("GSM-7"));
+    assert_eq!(preview.current_balance, Some(100));
+}
+
+#[tokio::test]
+async fn test_preview_invalid_phone() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .preview(SendMessageRequest::new("invalid-phone", "Hello"))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
 // ==================== list() Tests ====================
 
 #[tokio::test]
@@ -349,123 +550,402 @@ async fn test_list_with_to_filter() {
 }
 
 #[tokio::test]
-async fn test_list_authentication_error() {
+async fn test_list_with_created_range() {
     let mock_server = setup_mock_server().await;
 
     Mock::given(method("GET"))
         .and(path("/messages"))
-        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
-            "error": "Invalid API key"
+        .and(query_param("createdAfter", "2025-01-15T00:00:00Z"))
+        .and(query_param("createdBefore", "2025-01-16T00:00:00Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let client = create_test_client(&mock_server.uri());
 
-    let result = client.messages().list(None).await;
+    let options = ListMessagesOptions::new()
+        .created_after("2025-01-15T00:00:00Z")
+        .created_before("2025-01-16T00:00:00Z");
 
-    assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), Error::Authentication { .. }));
+    let result = client.messages().list(Some(options)).await;
+
+    assert!(result.is_ok());
 }
 
+#[cfg(feature = "chrono")]
 #[tokio::test]
-async fn test_list_not_found() {
+async fn test_list_with_created_range_datetime() {
+    use chrono::{TimeZone, Utc};
+
     let mock_server = setup_mock_server().await;
 
     Mock::given(method("GET"))
         .and(path("/messages"))
-        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
-            "error": "Resource not found"
+        .and(query_param("createdAfter", "2025-01-15T00:00:00+00:00"))
+        .and(query_param("createdBefore", "2025-01-16T00:00:00+00:00"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let client = create_test_client(&mock_server.uri());
 
-    let result = client.messages().list(None).await;
+    let options = ListMessagesOptions::new()
+        .created_after_datetime(Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap())
+        .created_before_datetime(Utc.with_ymd_and_hms(2025, 1, 16, 0, 0, 0).unwrap());
 
-    assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), Error::NotFound { .. }));
+    let result = client.messages().list(Some(options)).await;
+
+    assert!(result.is_ok());
 }
 
 #[tokio::test]
-async fn test_list_rate_limit() {
+async fn test_list_sort_asc() {
     let mock_server = setup_mock_server().await;
 
     Mock::given(method("GET"))
         .and(path("/messages"))
-        .respond_with(
-            ResponseTemplate::new(429)
-                .set_body_json(json!({"error": "Rate limit exceeded"}))
-                .insert_header("Retry-After", "30"),
-        )
+        .and(query_param("sort", "createdAt:asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let client = create_test_client(&mock_server.uri());
 
-    let result = client.messages().list(None).await;
+    let options = ListMessagesOptions::new().sort(SortOrder::Asc);
 
-    assert!(result.is_err());
-    match result.unwrap_err() {
-        Error::RateLimit { retry_after, .. } => {
-            assert_eq!(retry_after, Some(30));
-        }
-        _ => panic!("Expected RateLimit error"),
-    }
+    let result = client.messages().list(Some(options)).await;
+
+    assert!(result.is_ok());
 }
 
 #[tokio::test]
-async fn test_list_server_error() {
+async fn test_list_sandbox_filter() {
     let mock_server = setup_mock_server().await;
 
     Mock::given(method("GET"))
         .and(path("/messages"))
-        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
-            "error": "Internal server error"
+        .and(query_param("isSandbox", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{
+                "id": "msg_live",
+                "to": "+15551234567",
+                "text": "Hello",
+                "status": "delivered",
+                "isSandbox": false
+            }],
+            "count": 1
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     let client = create_test_client(&mock_server.uri());
 
-    let result = client.messages().list(None).await;
+    let options = ListMessagesOptions::new().sandbox(false);
 
-    assert!(result.is_err());
-    match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
-            assert_eq!(status_code, 500);
-        }
-        _ => panic!("Expected Api error"),
-    }
-}
+    let result = client.messages().list(Some(options)).await.unwrap();
 
-// ==================== get() Tests ====================
+    assert!(result.data[0].is_live());
+    assert!(!result.data[0].is_sandbox);
+}
 
 #[tokio::test]
-async fn test_get_success() {
+async fn test_count() {
     let mock_server = setup_mock_server().await;
-    mock_get_success().mount(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("limit", "0"))
+        .and(query_param("status", "failed"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "count": 12432
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
     let client = create_test_client(&mock_server.uri());
 
-    let result = client.messages().get("msg_abc123").await;
+    let options = ListMessagesOptions::new()
+        .limit(50)
+        .offset(100)
+        .status(MessageStatus::Failed);
 
-    assert!(result.is_ok());
-    let message = result.unwrap();
-    assert_eq!(message.id, "msg_abc123");
-    assert_eq!(message.status, MessageStatus::Delivered);
-    assert!(message.delivered_at.is_some());
+    let count = client.messages().count(Some(options)).await.unwrap();
+
+    assert_eq!(count, 12432);
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(!requests[0].url.query().unwrap().contains("offset"));
 }
 
 #[tokio::test]
-async fn test_get_empty_id() {
+async fn test_count_accepts_total() {
     let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "messages": [],
+            "total": 57
+        })))
+        .mount(&mock_server)
+        .await;
+
     let client = create_test_client(&mock_server.uri());
 
-    let result = client.messages().get("").await;
+    assert_eq!(client.messages().count(None).await.unwrap(), 57);
+}
 
-    assert!(result.is_err());
+#[tokio::test]
+async fn test_count_missing_field() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [] })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().count(None).await;
+
+    assert!(matches!(result, Err(Error::Json(_))));
+}
+
+#[tokio::test]
+async fn test_list_with_meta() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"data": [], "count": 0}))
+                .insert_header("X-RateLimit-Limit", "100")
+                .insert_header("X-RateLimit-Remaining", "42")
+                .insert_header("X-RateLimit-Reset", "1736935200")
+                .insert_header("X-Request-Id", "req_abc123"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let (list, meta) = client.messages().list_with_meta(None).await.unwrap();
+
+    assert!(list.data.is_empty());
+    let rate_limit = meta.rate_limit.unwrap();
+    assert_eq!(rate_limit.limit, Some(100));
+    assert_eq!(rate_limit.remaining, 42);
+    assert_eq!(rate_limit.reset, Some(1736935200));
+    assert_eq!(meta.request_id.as_deref(), Some("req_abc123"));
+}
+
+#[tokio::test]
+async fn test_list_with_meta_without_headers() {
+    let mock_server = setup_mock_server().await;
+    mock_list_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let (_, meta) = client.messages().list_with_meta(None).await.unwrap();
+
+    assert_eq!(meta, sendly::ResponseMeta::default());
+}
+
+#[tokio::test]
+async fn test_list_sort_desc() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("sort", "createdAt:desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListMessagesOptions::new().sort(SortOrder::Desc);
+
+    let result = client.messages().list(Some(options)).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_list_authentication_error() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Invalid API key"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().list(None).await;
+
+    assert!(result.is_err());
+    assert!(matches!(result.unwrap_err(), Error::Authentication { .. }));
+}
+
+#[tokio::test]
+async fn test_list_not_found() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Resource not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().list(None).await;
+
+    assert!(result.is_err());
+    assert!(matches!(result.unwrap_err(), Error::NotFound { .. }));
+}
+
+#[tokio::test]
+async fn test_list_rate_limit() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .set_body_json(json!({"error": "Rate limit exceeded"}))
+                .insert_header("Retry-After", "30"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().list(None).await;
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        Error::RateLimit { retry_after, .. } => {
+            assert_eq!(retry_after, Some(30));
+        }
+        _ => panic!("Expected RateLimit error"),
+    }
+}
+
+#[tokio::test]
+async fn test_list_server_error() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "error": "Internal server error"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().list(None).await;
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        Error::ServerError { status_code, .. } => {
+            assert_eq!(status_code, 500);
+        }
+        _ => panic!("Expected ServerError"),
+    }
+}
+
+// ==================== get() Tests ====================
+
+#[tokio::test]
+async fn test_get_success() {
+    let mock_server = setup_mock_server().await;
+    mock_get_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().get("msg_abc123").await;
+
+    assert!(result.is_ok());
+    let message = result.unwrap();
+    assert_eq!(message.id, "msg_abc123");
+    assert_eq!(message.status, MessageStatus::Delivered);
+    assert!(message.delivered_at.is_some());
+}
+
+#[tokio::test]
+async fn test_get_with_metadata() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/msg_abc123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "msg_abc123",
+            "to": "+15551234567",
+            "text": "Your order shipped",
+            "status": "delivered",
+            "metadata": { "orderId": "ord_42", "attempt": 2 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let message = client.messages().get("msg_abc123").await.unwrap();
+
+    let metadata = message.metadata.expect("metadata should be present");
+    assert_eq!(metadata["orderId"], "ord_42");
+    assert_eq!(metadata["attempt"], 2);
+}
+
+#[tokio::test]
+async fn test_get_without_metadata() {
+    let mock_server = setup_mock_server().await;
+    mock_get_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let message = client.messages().get("msg_abc123").await.unwrap();
+
+    assert!(message.metadata.is_none());
+}
+
+#[tokio::test]
+async fn test_get_empty_id() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().get("").await;
+
+    assert!(result.is_err());
     match result.unwrap_err() {
         Error::Validation { message } => {
             assert!(message.contains("Message ID is required"));
@@ -557,88 +1037,452 @@ async fn test_get_server_error() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        Error::Api { status_code, .. } => {
+        Error::ServerError { status_code, .. } => {
             assert_eq!(status_code, 500);
         }
-        _ => panic!("Expected Api error"),
+        _ => panic!("Expected ServerError"),
     }
 }
 
-// ==================== iter() Tests ====================
+// ==================== get_many() Tests ====================
 
 #[tokio::test]
-async fn test_iter_success() {
+async fn test_get_many_mixed_results() {
     let mock_server = setup_mock_server().await;
 
-    // First page
+    for id in ["msg_1", "msg_3"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/messages/{}", id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": id,
+                "to": "+15551234567",
+                "text": "Hello",
+                "status": "delivered"
+            })))
+            .mount(&mock_server)
+            .await;
+    }
     Mock::given(method("GET"))
-        .and(path("/messages"))
-        .and(query_param("limit", "100"))
-        .and(query_param("offset", "0"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "data": [
-                {
-                    "id": "msg_1",
-                    "to": "+15551111111",
-                    "text": "Message 1",
-                    "status": "delivered",
-                    "segments": 1,
-                    "creditsUsed": 1,
-                    "isSandbox": false
-                },
-                {
-                    "id": "msg_2",
-                    "to": "+15552222222",
-                    "text": "Message 2",
-                    "status": "delivered",
-                    "segments": 1,
-                    "creditsUsed": 1,
-                    "isSandbox": false
-                }
-            ],
-            "count": 2
+        .and(path("/messages/msg_2"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Message not found"
         })))
         .mount(&mock_server)
         .await;
 
     let client = create_test_client(&mock_server.uri());
 
-    let messages_api = client.messages();
-    let stream = messages_api.iter(None);
-    futures::pin_mut!(stream);
-    let mut messages = Vec::new();
+    let ids: Vec<String> = ["msg_1", "msg_2", "msg_3"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let results = client.messages().get_many(&ids).await.unwrap();
 
-    while let Some(result) = stream.next().await {
-        messages.push(result.unwrap());
-    }
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().id, "msg_1");
+    assert!(matches!(results[1], Err(Error::NotFound { .. })));
+    assert_eq!(results[2].as_ref().unwrap().id, "msg_3");
+}
 
-    assert_eq!(messages.len(), 2);
-    assert_eq!(messages[0].id, "msg_1");
-    assert_eq!(messages[1].id, "msg_2");
+#[tokio::test]
+async fn test_get_many_empty() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let results = client.messages().get_many(&[]).await.unwrap();
+
+    assert!(results.is_empty());
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
 }
 
+// ==================== events() Tests ====================
+
 #[tokio::test]
-async fn test_iter_pagination() {
+async fn test_events_timeline() {
     let mock_server = setup_mock_server().await;
 
-    // First page
     Mock::given(method("GET"))
-        .and(path("/messages"))
-        .and(query_param("limit", "2"))
-        .and(query_param("offset", "0"))
+        .and(path("/messages/msg_abc123/events"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "data": [
-                {"id": "msg_1", "to": "+15551111111", "text": "1", "status": "delivered", "segments": 1, "creditsUsed": 1, "isSandbox": false},
-                {"id": "msg_2", "to": "+15552222222", "text": "2", "status": "delivered", "segments": 1, "creditsUsed": 1, "isSandbox": false}
-            ],
-            "count": 3
+            "events": [
+                { "status": "queued", "timestamp": "2025-01-15T10:00:00Z" },
+                { "status": "sent", "timestamp": "2025-01-15T10:00:02Z", "detail": "Accepted by carrier" },
+                { "status": "delivered", "timestamp": "2025-01-15T10:00:05Z", "detail": null }
+            ]
         })))
         .mount(&mock_server)
         .await;
 
-    // Second page
-    Mock::given(method("GET"))
-        .and(path("/messages"))
+    let client = create_test_client(&mock_server.uri());
+
+    let events = client.messages().events("msg_abc123").await.unwrap();
+
+    assert_eq!(events.len(), 3);
+    let statuses: Vec<Option<MessageStatus>> = events.iter().map(|e| e.message_status()).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            Some(MessageStatus::Queued),
+            Some(MessageStatus::Sent),
+            Some(MessageStatus::Delivered)
+        ]
+    );
+    assert_eq!(events[0].timestamp, "2025-01-15T10:00:00Z");
+    assert_eq!(events[1].detail.as_deref(), Some("Accepted by carrier"));
+    assert!(events[2].detail.is_none());
+}
+
+#[tokio::test]
+async fn test_events_bare_array() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/msg_abc123/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "status": "accepted", "timestamp": "2025-01-15T10:00:00Z" }
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let events = client.messages().events("msg_abc123").await.unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].status, "accepted");
+    assert!(events[0].message_status().is_none());
+}
+
+#[tokio::test]
+async fn test_events_unexpected_shape() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/msg_abc123/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "timeline": [
+                { "status": "queued", "timestamp": "2025-01-15T10:00:00Z" }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().events("msg_abc123").await;
+
+    assert!(matches!(result, Err(Error::Http(e)) if e.is_decode()));
+}
+
+#[tokio::test]
+async fn test_events_empty_id() {
+    let client = create_test_client("http://localhost");
+
+    let result = client.messages().events("").await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+// ==================== exists() Tests ====================
+
+#[tokio::test]
+async fn test_exists_found() {
+    let mock_server = setup_mock_server().await;
+    mock_get_success().mount(&mock_server).await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().exists("msg_abc123").await;
+
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn test_exists_not_found() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/msg_missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Message not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().exists("msg_missing").await;
+
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn test_exists_propagates_other_errors() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/msg_missing"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Invalid API key"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().exists("msg_missing").await;
+
+    assert!(matches!(result, Err(Error::Authentication { .. })));
+}
+
+// ==================== cancel() Tests ====================
+
+#[tokio::test]
+async fn test_cancel_queued_message() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/msg_abc123/cancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "msg_abc123",
+            "to": "+15551234567",
+            "text": "Hello",
+            "status": "cancelled"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let message = client.messages().cancel("msg_abc123").await.unwrap();
+
+    assert_eq!(message.id, "msg_abc123");
+    assert_eq!(message.status, MessageStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_cancel_already_sent() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/msg_abc123/cancel"))
+        .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+            "message": "Message has already been sent"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().cancel("msg_abc123").await;
+
+    match result {
+        Err(Error::Conflict { message }) => assert!(message.contains("already been sent")),
+        other => panic!("Expected Conflict error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_empty_id() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.messages().cancel("").await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+// ==================== next_page() Tests ====================
+
+fn page_of(ids: &[&str], count: i32) -> serde_json::Value {
+    let data: Vec<serde_json::Value> = ids
+        .iter()
+        .map(|id| {
+            json!({
+                "id": id,
+                "to": "+15551111111",
+                "text": "Hello",
+                "status": "delivered"
+            })
+        })
+        .collect();
+    json!({ "data": data, "count": count })
+}
+
+#[tokio::test]
+async fn test_next_page_manual_paging() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "0"))
+        .and(query_param("status", "delivered"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_of(&["msg_1", "msg_2"], 3)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "2"))
+        .and(query_param("status", "delivered"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_of(&["msg_3"], 3)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let options = ListMessagesOptions::new()
+        .limit(2)
+        .status(MessageStatus::Delivered);
+
+    let mut ids = Vec::new();
+    let mut offsets = Vec::new();
+    let mut offset = Some(0);
+    while let Some(current) = offset {
+        let (page, next) = client
+            .messages()
+            .next_page(Some(options.clone()), current)
+            .await
+            .unwrap();
+        ids.extend(page.data.into_iter().map(|m| m.id));
+        offsets.push(next);
+        offset = next;
+    }
+
+    assert_eq!(ids, vec!["msg_1", "msg_2", "msg_3"]);
+    assert_eq!(offsets, vec![Some(2), None]);
+}
+
+#[tokio::test]
+async fn test_next_page_stops_at_reported_total() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_of(&["msg_1", "msg_2"], 2)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let (page, next) = client
+        .messages()
+        .next_page(Some(ListMessagesOptions::new().limit(2)), 0)
+        .await
+        .unwrap();
+
+    assert_eq!(page.len(), 2);
+    assert_eq!(next, None);
+}
+
+#[tokio::test]
+async fn test_next_page_agrees_with_has_more() {
+    let mock_server = setup_mock_server().await;
+
+    // The server caps the page at 2 and echoes that, without a total
+    let mut capped = page_of(&["msg_1", "msg_2"], 0);
+    capped["limit"] = json!(2);
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(capped))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let (page, next) = client
+        .messages()
+        .next_page(Some(ListMessagesOptions::new().limit(100)), 0)
+        .await
+        .unwrap();
+
+    assert!(page.has_more());
+    assert_eq!(next, Some(2));
+}
+
+// ==================== iter() Tests ====================
+
+#[tokio::test]
+async fn test_iter_success() {
+    let mock_server = setup_mock_server().await;
+
+    // First page
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("limit", "100"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [
+                {
+                    "id": "msg_1",
+                    "to": "+15551111111",
+                    "text": "Message 1",
+                    "status": "delivered",
+                    "segments": 1,
+                    "creditsUsed": 1,
+                    "isSandbox": false
+                },
+                {
+                    "id": "msg_2",
+                    "to": "+15552222222",
+                    "text": "Message 2",
+                    "status": "delivered",
+                    "segments": 1,
+                    "creditsUsed": 1,
+                    "isSandbox": false
+                }
+            ],
+            "count": 2
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let messages_api = client.messages();
+    let stream = messages_api.iter(None);
+    futures::pin_mut!(stream);
+    let mut messages = Vec::new();
+
+    while let Some(result) = stream.next().await {
+        messages.push(result.unwrap());
+    }
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].id, "msg_1");
+    assert_eq!(messages[1].id, "msg_2");
+}
+
+#[tokio::test]
+async fn test_iter_pagination() {
+    let mock_server = setup_mock_server().await;
+
+    // First page
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [
+                {"id": "msg_1", "to": "+15551111111", "text": "1", "status": "delivered", "segments": 1, "creditsUsed": 1, "isSandbox": false},
+                {"id": "msg_2", "to": "+15552222222", "text": "2", "status": "delivered", "segments": 1, "creditsUsed": 1, "isSandbox": false}
+            ],
+            "count": 3
+        })))
+        .mount(&mock_server)
+        .await;
+
+    // Second page
+    Mock::given(method("GET"))
+        .and(path("/messages"))
         .and(query_param("limit", "2"))
         .and(query_param("offset", "2"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
@@ -725,3 +1569,463 @@ async fn test_iter_error_handling() {
         panic!("Expected error from stream");
     }
 }
+
+#[tokio::test]
+async fn test_iter_keeps_created_range() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("createdAfter", "2025-01-15T00:00:00Z"))
+        .and(query_param("createdBefore", "2025-01-16T00:00:00Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [
+                {
+                    "id": "msg_1",
+                    "to": "+15551111111",
+                    "text": "Message 1",
+                    "status": "delivered",
+                    "segments": 1,
+                    "creditsUsed": 1,
+                    "isSandbox": false
+                }
+            ],
+            "count": 1
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let options = ListMessagesOptions::new()
+        .limit(10)
+        .created_after("2025-01-15T00:00:00Z")
+        .created_before("2025-01-16T00:00:00Z");
+    let messages = client.messages();
+    let stream = messages.iter(Some(options));
+    futures::pin_mut!(stream);
+
+    let mut count = 0;
+    while let Some(result) = stream.next().await {
+        result.unwrap();
+        count += 1;
+    }
+
+    assert_eq!(count, 1);
+}
+
+#[tokio::test]
+async fn test_iter_stops_at_total_on_full_last_page() {
+    let mock_server = setup_mock_server().await;
+
+    let message = |id: &str| {
+        json!({
+            "id": id,
+            "to": "+15551111111",
+            "text": "Message",
+            "status": "delivered"
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [message("msg_1"), message("msg_2")],
+            "count": 4
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [message("msg_3"), message("msg_4")],
+            "count": 4
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("offset", "4"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 4
+        })))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let messages_api = client.messages();
+    let stream = messages_api.iter(Some(ListMessagesOptions::new().limit(2)));
+    futures::pin_mut!(stream);
+    let mut ids = Vec::new();
+
+    while let Some(result) = stream.next().await {
+        ids.push(result.unwrap().id);
+    }
+
+    assert_eq!(ids, vec!["msg_1", "msg_2", "msg_3", "msg_4"]);
+}
+
+#[tokio::test]
+async fn test_iter_zero_limit_terminates() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .and(query_param("limit", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let messages = client.messages();
+    let stream = messages.iter(Some(ListMessagesOptions::new().limit(0)));
+    futures::pin_mut!(stream);
+
+    let next = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next())
+        .await
+        .expect("iter with limit(0) should terminate");
+
+    assert!(next.is_none());
+}
+
+// ==================== iter_buffered() Tests ====================
+
+#[tokio::test]
+async fn test_iter_buffered_preserves_order() {
+    let mock_server = setup_mock_server().await;
+
+    let message = |id: &str| {
+        json!({
+            "id": id,
+            "to": "+15551111111",
+            "text": "Message",
+            "status": "delivered"
+        })
+    };
+
+    // The first page is the slowest, so it completes last
+    let pages = [
+        ("0", vec!["msg_1", "msg_2"], 300),
+        ("2", vec!["msg_3", "msg_4"], 100),
+        ("4", vec!["msg_5"], 0),
+    ];
+    for (offset, ids, delay_ms) in pages {
+        let data: Vec<_> = ids.into_iter().map(message).collect();
+        Mock::given(method("GET"))
+            .and(path("/messages"))
+            .and(query_param("limit", "2"))
+            .and(query_param("offset", offset))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": data, "count": 5 }))
+                    .set_delay(std::time::Duration::from_millis(delay_ms)),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = create_test_client(&mock_server.uri());
+
+    let messages_api = client.messages();
+    let stream = messages_api.iter_buffered(Some(ListMessagesOptions::new().limit(2)), 3);
+    futures::pin_mut!(stream);
+    let mut ids = Vec::new();
+
+    while let Some(result) = stream.next().await {
+        ids.push(result.unwrap().id);
+    }
+
+    assert_eq!(ids, vec!["msg_1", "msg_2", "msg_3", "msg_4", "msg_5"]);
+}
+
+// ==================== default_from Tests ====================
+
+fn default_from_client(base_url: &str) -> sendly::Sendly {
+    let config = sendly::SendlyConfig::new()
+        .base_url(base_url)
+        .max_retries(0)
+        .default_from("ACME");
+
+    sendly::Sendly::with_config(common::TEST_API_KEY, config)
+}
+
+async fn sent_from(mock_server: &wiremock::MockServer) -> Option<String> {
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    body["from"].as_str().map(String::from)
+}
+
+#[tokio::test]
+async fn test_send_uses_default_from() {
+    let mock_server = setup_mock_server().await;
+
+    mock_send_success().mount(&mock_server).await;
+
+    let client = default_from_client(&mock_server.uri());
+
+    let result = client.messages().send_to("+15551234567", "Hello").await;
+
+    assert!(result.is_ok());
+    assert_eq!(sent_from(&mock_server).await.as_deref(), Some("ACME"));
+}
+
+#[tokio::test]
+async fn test_send_explicit_from_overrides_default() {
+    let mock_server = setup_mock_server().await;
+
+    mock_send_success().mount(&mock_server).await;
+
+    let client = default_from_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send(SendMessageRequest::new("+15551234567", "Hello").from("OTHER"))
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(sent_from(&mock_server).await.as_deref(), Some("OTHER"));
+}
+
+#[tokio::test]
+async fn test_send_batch_uses_default_from() {
+    let mock_server = setup_mock_server().await;
+
+    common::mock_batch_send_success().mount(&mock_server).await;
+
+    let client = default_from_client(&mock_server.uri());
+
+    let result = client
+        .messages()
+        .send_batch(sendly::SendBatchRequest {
+            messages: vec![sendly::BatchMessageItem {
+                to: "+15551234567".to_string(),
+                text: "Hello".to_string(),
+                variables: None,
+                metadata: None,
+            }],
+            from: None,
+            message_type: None,
+            metadata: None,
+            template_id: None,
+        })
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(sent_from(&mock_server).await.as_deref(), Some("ACME"));
+}
+
+// ==================== list_streaming() Tests ====================
+
+fn large_page(n: usize) -> serde_json::Value {
+    let data: Vec<serde_json::Value> = (0..n)
+        .map(|i| {
+            json!({
+                "id": format!("msg_{}", i),
+                "to": "+15551234567",
+                "text": format!("Message {} with [brackets], {{braces}} and \"quotes\"", i),
+                "status": "delivered",
+                "metadata": { "nested": [i, { "deep": true }] }
+            })
+        })
+        .collect();
+
+    json!({ "count": n, "data": data, "hasMore": false })
+}
+
+#[tokio::test]
+async fn test_list_streaming_large_page() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(large_page(5000)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+    let mut stream = Box::pin(messages.list_streaming(None));
+
+    // Items are yielded one at a time as they are parsed
+    let first = stream.next().await.unwrap().unwrap();
+    assert_eq!(first.id, "msg_0");
+    assert_eq!(
+        first.text,
+        "Message 0 with [brackets], {braces} and \"quotes\""
+    );
+
+    let mut count = 1;
+    let mut last_id = String::new();
+    while let Some(message) = stream.next().await {
+        last_id = message.unwrap().id;
+        count += 1;
+    }
+
+    assert_eq!(count, 5000);
+    assert_eq!(last_id, "msg_4999");
+}
+
+#[tokio::test]
+async fn test_list_streaming_empty_page() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [],
+            "count": 0
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let items: Vec<_> = messages.list_streaming(None).collect().await;
+
+    assert!(items.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_streaming_messages_key() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total": 2,
+            "messages": [
+                { "id": "msg_1", "to": "+15551234567", "text": "a", "status": "sent" },
+                { "id": "msg_2", "to": "+15551234567", "text": "b", "status": "sent" }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let items: Vec<_> = messages.list_streaming(None).collect().await;
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1].as_ref().unwrap().id, "msg_2");
+}
+
+#[tokio::test]
+async fn test_list_streaming_missing_list_key() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "count": 2,
+            "results": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let items: Vec<_> = messages.list_streaming(None).collect().await;
+
+    assert_eq!(items.len(), 1);
+    assert!(matches!(items[0], Err(Error::Json(_))));
+}
+
+#[tokio::test]
+async fn test_list_streaming_error_status() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Invalid API key"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let items: Vec<_> = messages.list_streaming(None).collect().await;
+
+    assert_eq!(items.len(), 1);
+    assert!(matches!(items[0], Err(Error::Authentication { .. })));
+}
+
+#[tokio::test]
+async fn test_list_streaming_malformed_body() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"data": [{"id": "msg_1""#))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+    let messages = client.messages();
+
+    let items: Vec<_> = messages.list_streaming(None).collect().await;
+
+    assert!(matches!(items.last(), Some(Err(Error::Json(_)))));
+}
+
+#[tokio::test]
+async fn test_list_streaming_max_response_bytes() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(large_page(5000)))
+        .mount(&mock_server)
+        .await;
+
+    let config = sendly::SendlyConfig::new()
+        .base_url(mock_server.uri())
+        .max_retries(0)
+        .max_response_bytes(64 * 1024);
+    let client = sendly::Sendly::with_config(common::TEST_API_KEY, config);
+    let messages = client.messages();
+
+    let items: Vec<_> = messages.list_streaming(None).collect().await;
+
+    // Items within the limit are still yielded before the stream fails
+    assert!(items.len() > 1);
+    assert!(items[..items.len() - 1].iter().all(|item| item.is_ok()));
+    match items.last() {
+        Some(Err(Error::Validation { message })) => {
+            assert!(message.contains("Response too large"))
+        }
+        other => panic!("Expected response too large, got: {:?}", other),
+    }
+
+    // A page under the limit streams normally
+    let config = sendly::SendlyConfig::new()
+        .base_url(mock_server.uri())
+        .max_response_bytes(64 * 1024 * 1024);
+    let client = sendly::Sendly::with_config(common::TEST_API_KEY, config);
+    let messages = client.messages();
+
+    let items: Vec<_> = messages.list_streaming(None).collect().await;
+    assert_eq!(items.len(), 5000);
+    assert!(items.iter().all(|item| item.is_ok()));
+}
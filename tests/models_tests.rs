@@ -0,0 +1,552 @@
+use sendly::{
+    Account, BatchList, BatchStatus, CampaignStatus, CircuitState, CreditTransaction, Credits,
+    Currency, Error, Message, MessageErrorCategory, MessageList, MessageStatus, MessageType,
+    ScheduledMessageList, ScheduledMessageStatus, Secret, SendMessageRequest, TransactionType,
+    Webhook, WebhookCreatedResponse,
+};
+use serde_json::json;
+
+// ==================== Status FromStr Tests ====================
+
+#[test]
+fn test_message_status_round_trip() {
+    for status in [
+        MessageStatus::Queued,
+        MessageStatus::Sent,
+        MessageStatus::Delivered,
+        MessageStatus::Failed,
+        MessageStatus::Bounced,
+        MessageStatus::Cancelled,
+    ] {
+        assert_eq!(status.as_str().parse::<MessageStatus>().unwrap(), status);
+        assert_eq!(status.to_string(), status.as_str());
+    }
+}
+
+#[test]
+fn test_scheduled_message_status_round_trip() {
+    for status in [
+        ScheduledMessageStatus::Scheduled,
+        ScheduledMessageStatus::Sent,
+        ScheduledMessageStatus::Cancelled,
+        ScheduledMessageStatus::Failed,
+    ] {
+        assert_eq!(
+            status.as_str().parse::<ScheduledMessageStatus>().unwrap(),
+            status
+        );
+    }
+}
+
+#[test]
+fn test_batch_status_round_trip() {
+    for status in [
+        BatchStatus::Processing,
+        BatchStatus::Completed,
+        BatchStatus::PartialFailure,
+        BatchStatus::Failed,
+    ] {
+        assert_eq!(status.as_str().parse::<BatchStatus>().unwrap(), status);
+    }
+}
+
+#[test]
+fn test_campaign_status_round_trip() {
+    for status in [
+        CampaignStatus::Draft,
+        CampaignStatus::Scheduled,
+        CampaignStatus::Sending,
+        CampaignStatus::Sent,
+        CampaignStatus::Paused,
+        CampaignStatus::Cancelled,
+        CampaignStatus::Failed,
+    ] {
+        assert_eq!(status.as_str().parse::<CampaignStatus>().unwrap(), status);
+    }
+}
+
+#[test]
+fn test_status_parse_unknown() {
+    let result = "delivred".parse::<MessageStatus>();
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+// ==================== Account Tests ====================
+
+#[test]
+fn test_account_full_payload() {
+    let account: Account = serde_json::from_value(json!({
+        "id": "acc_abc123",
+        "email": "ops@example.com",
+        "name": "Ops",
+        "companyName": "Example Inc",
+        "verification": {
+            "status": "approved",
+            "verified": true,
+            "submittedAt": "2025-01-10T09:00:00Z"
+        },
+        "limits": {
+            "dailyMessageLimit": 500,
+            "monthlyMessageLimit": 12000,
+            "rateLimitPerSecond": 5
+        },
+        "createdAt": "2025-01-01T00:00:00Z"
+    }))
+    .unwrap();
+
+    assert_eq!(account.company_name.as_deref(), Some("Example Inc"));
+    assert_eq!(account.verification.status.as_deref(), Some("approved"));
+    assert_eq!(
+        account.verification.submitted_at.as_deref(),
+        Some("2025-01-10T09:00:00Z")
+    );
+    assert!(account.is_verified());
+    assert_eq!(account.limits.messages_per_day, 500);
+    assert_eq!(account.limits.monthly_message_limit, Some(12000));
+    assert_eq!(account.limits.messages_per_second, 5);
+    assert!(account.within_daily_limit(499));
+    assert!(!account.within_daily_limit(500));
+}
+
+#[test]
+fn test_account_defaults_when_sections_missing() {
+    let account: Account = serde_json::from_value(json!({"id": "acc_abc123"})).unwrap();
+
+    assert!(!account.is_verified());
+    assert_eq!(account.limits.messages_per_day, 10000);
+    assert_eq!(account.limits.monthly_message_limit, None);
+}
+
+// ==================== Currency Tests ====================
+
+#[test]
+fn test_currency_known() {
+    let credits: Credits = serde_json::from_value(json!({
+        "availableBalance": 10,
+        "currency": "EUR"
+    }))
+    .unwrap();
+
+    assert_eq!(credits.currency, Currency::Eur);
+    assert_eq!(serde_json::to_value(&credits.currency).unwrap(), "EUR");
+}
+
+#[test]
+fn test_currency_unknown() {
+    let credits: Credits = serde_json::from_value(json!({
+        "availableBalance": 10,
+        "currency": "gbp"
+    }))
+    .unwrap();
+
+    assert_eq!(credits.currency, Currency::Other("GBP".to_string()));
+    assert_eq!(credits.currency.to_string(), "GBP");
+}
+
+#[test]
+fn test_currency_defaults_to_usd() {
+    let credits: Credits = serde_json::from_value(json!({ "availableBalance": 10 })).unwrap();
+
+    assert_eq!(credits.currency, Currency::Usd);
+}
+
+// ==================== Message Encoding Tests ====================
+
+#[test]
+fn test_message_encoding_ucs2() {
+    let message: Message = serde_json::from_value(json!({
+        "id": "msg_abc123",
+        "to": "+15551234567",
+        "text": "Hello 👋",
+        "status": "sent",
+        "segments": 1,
+        "encoding": "UCS-2"
+    }))
+    .unwrap();
+
+    assert_eq!(message.encoding.as_deref(), Some("UCS-2"));
+    assert!(message.is_unicode());
+}
+
+#[test]
+fn test_message_encoding_gsm7_and_missing() {
+    let gsm: Message = serde_json::from_value(json!({
+        "id": "msg_abc123",
+        "to": "+15551234567",
+        "text": "Hello",
+        "status": "sent",
+        "encoding": "GSM-7"
+    }))
+    .unwrap();
+    assert!(!gsm.is_unicode());
+
+    let missing: Message = serde_json::from_value(json!({
+        "id": "msg_abc123",
+        "to": "+15551234567",
+        "text": "Hello",
+        "status": "sent"
+    }))
+    .unwrap();
+    assert_eq!(missing.encoding, None);
+    assert!(!missing.is_unicode());
+}
+
+// ==================== MessageError Tests ====================
+
+fn failed_message(error_code: Option<&str>) -> Message {
+    serde_json::from_value(json!({
+        "id": "msg_abc123",
+        "to": "+15551234567",
+        "text": "Hello",
+        "status": "failed",
+        "errorCode": error_code,
+        "errorMessage": "Delivery failed"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_delivery_error_known_code() {
+    let message = failed_message(Some("30003"));
+
+    let error = message.delivery_error().unwrap();
+    assert_eq!(error.code.as_deref(), Some("30003"));
+    assert_eq!(error.message.as_deref(), Some("Delivery failed"));
+    assert_eq!(error.category(), MessageErrorCategory::Undeliverable);
+
+    assert_eq!(
+        MessageErrorCategory::from_code("carrier_violation"),
+        MessageErrorCategory::CarrierRejected
+    );
+    assert_eq!(
+        MessageErrorCategory::from_code("21610"),
+        MessageErrorCategory::Blocked
+    );
+}
+
+#[test]
+fn test_delivery_error_unknown_code() {
+    let message = failed_message(Some("99999"));
+
+    assert_eq!(
+        message.delivery_error().unwrap().category(),
+        MessageErrorCategory::Unknown
+    );
+}
+
+#[test]
+fn test_delivery_error_absent() {
+    let message: Message = serde_json::from_value(json!({
+        "id": "msg_abc123",
+        "to": "+15551234567",
+        "text": "Hello",
+        "status": "delivered"
+    }))
+    .unwrap();
+
+    assert!(message.delivery_error().is_none());
+}
+
+// ==================== Secret Tests ====================
+
+#[test]
+fn test_secret_is_redacted() {
+    let secret = Secret::new("whsec_super_secret_value");
+
+    assert!(!format!("{:?}", secret).contains("whsec_super_secret_value"));
+    assert_eq!(format!("{}", secret), "***");
+    assert_eq!(secret.expose(), "whsec_super_secret_value");
+}
+
+#[test]
+fn test_webhook_created_response_redacts_secret() {
+    let response: WebhookCreatedResponse = serde_json::from_value(json!({
+        "id": "whk_abc123",
+        "url": "https://example.com/webhook",
+        "secret": "whsec_super_secret_value"
+    }))
+    .unwrap();
+
+    assert!(!format!("{:?}", response).contains("whsec_super_secret_value"));
+    assert_eq!(response.secret.expose(), "whsec_super_secret_value");
+}
+
+// ==================== Webhook Health Tests ====================
+
+#[test]
+fn test_webhook_circuit_and_health() {
+    let open: Webhook = serde_json::from_value(json!({
+        "id": "whk_abc123",
+        "url": "https://example.com/hook",
+        "circuitState": "open",
+        "failureCount": 12,
+        "totalDeliveries": 100,
+        "successRate": 99.0
+    }))
+    .unwrap();
+
+    assert!(open.is_circuit_open());
+    assert!(!open.is_healthy_with_rate(95.0));
+
+    let closed = Webhook {
+        circuit_state: CircuitState::Closed,
+        ..open
+    };
+    assert!(closed.is_healthy_with_rate(95.0));
+    assert!(!closed.is_healthy_with_rate(99.5));
+
+    let half_open = Webhook {
+        circuit_state: CircuitState::HalfOpen,
+        ..closed.clone()
+    };
+    assert!(!half_open.is_circuit_open());
+    assert!(!half_open.is_healthy_with_rate(0.0));
+
+    // No deliveries yet: nothing to judge the rate by
+    let fresh = Webhook::default();
+    assert!(fresh.is_healthy_with_rate(95.0));
+}
+
+// ==================== CreditTransaction Tests ====================
+
+fn transaction(kind: &str, amount: i32) -> CreditTransaction {
+    serde_json::from_value(json!({
+        "id": "tx_1",
+        "type": kind,
+        "amount": amount,
+        "balanceAfter": 500,
+        "createdAt": "2025-01-15T10:00:00Z"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_credit_transaction_kinds() {
+    let cases = [
+        ("purchase", TransactionType::Purchase),
+        ("usage", TransactionType::Usage),
+        ("refund", TransactionType::Refund),
+        ("bonus", TransactionType::Bonus),
+        ("adjustment", TransactionType::Adjustment),
+    ];
+
+    for (wire, expected) in cases {
+        let tx = transaction(wire, 10);
+        assert_eq!(tx.transaction_type, expected);
+        assert_eq!(tx.transaction_type.as_str(), wire);
+        assert_eq!(tx.balance_after, 500);
+        assert_eq!(tx.created_at.as_deref(), Some("2025-01-15T10:00:00Z"));
+    }
+}
+
+#[test]
+fn test_credit_transaction_unknown_kind() {
+    let tx = transaction("chargeback", -10);
+
+    assert_eq!(tx.transaction_type, TransactionType::Unknown);
+}
+
+#[test]
+fn test_credit_transaction_is_debit() {
+    assert!(transaction("usage", -2).is_debit());
+    assert!(!transaction("purchase", 1000).is_debit());
+    assert!(transaction("purchase", 1000).is_credit());
+}
+
+// ==================== SendMessageRequest Tests ====================
+
+#[test]
+fn test_send_message_request_builder() {
+    let minimal = SendMessageRequest::new("+15551234567", "Hello");
+    assert_eq!(
+        serde_json::to_value(&minimal).unwrap(),
+        json!({"to": "+15551234567", "text": "Hello"})
+    );
+
+    let metadata = [("order".to_string(), json!(42))].into_iter().collect();
+    let full = SendMessageRequest::new("+15551234567", "Hello")
+        .from("ACME")
+        .message_type(MessageType::Transactional)
+        .metadata(metadata);
+    assert_eq!(
+        serde_json::to_value(&full).unwrap(),
+        json!({
+            "to": "+15551234567",
+            "text": "Hello",
+            "from": "ACME",
+            "messageType": "transactional",
+            "metadata": {"order": 42}
+        })
+    );
+}
+
+// ==================== MessageList Shape Tests ====================
+
+#[test]
+fn test_message_list_data_count_shape() {
+    let list: MessageList = serde_json::from_value(json!({
+        "data": [{"id": "msg_1", "to": "+15551234567", "text": "a", "status": "sent"}],
+        "count": 7
+    }))
+    .unwrap();
+
+    assert_eq!(list.len(), 1);
+    assert_eq!(list.count, 7);
+}
+
+#[test]
+fn test_message_list_messages_total_shape() {
+    let list: MessageList = serde_json::from_value(json!({
+        "messages": [
+            {"id": "msg_1", "to": "+15551234567", "text": "a", "status": "sent"},
+            {"id": "msg_2", "to": "+15551234567", "text": "b", "status": "sent"}
+        ],
+        "total": 9,
+        "limit": 2,
+        "offset": 0
+    }))
+    .unwrap();
+
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.count, 9);
+    assert_eq!(list.limit, Some(2));
+    assert!(list.has_more());
+}
+
+#[test]
+fn test_message_list_without_messages_fails() {
+    let result: Result<MessageList, _> = serde_json::from_value(json!({"count": 0}));
+
+    assert!(result.unwrap_err().to_string().contains("data"));
+}
+
+// ==================== Aggregation Tests ====================
+
+fn batch(sent: i32, total: i32, credits_used: i32) -> serde_json::Value {
+    json!({
+        "batchId": "batch_1",
+        "status": "completed",
+        "total": total,
+        "queued": 0,
+        "sent": sent,
+        "failed": total - sent,
+        "creditsUsed": credits_used
+    })
+}
+
+#[test]
+fn test_message_list_total_credits() {
+    let list: MessageList = serde_json::from_value(json!({
+        "data": [
+            {"id": "msg_1", "to": "+15551234567", "text": "a", "status": "sent", "creditsUsed": 1},
+            {"id": "msg_2", "to": "+15551234567", "text": "b", "status": "sent", "creditsUsed": 3}
+        ],
+        "count": 2
+    }))
+    .unwrap();
+
+    assert_eq!(list.total_credits(), 4);
+}
+
+#[test]
+fn test_batch_success_rate_and_total_credits() {
+    let list: BatchList = serde_json::from_value(json!({
+        "data": [batch(3, 4, 3), batch(0, 0, 0)],
+        "count": 2
+    }))
+    .unwrap();
+
+    assert_eq!(list.data[0].success_rate(), 0.75);
+    assert_eq!(list.data[1].success_rate(), 0.0);
+    assert_eq!(list.total_credits(), 3);
+}
+
+// ==================== List Iteration Tests ====================
+
+#[test]
+fn test_list_types_iterate_by_reference() {
+    let messages: MessageList = serde_json::from_value(json!({
+        "data": [
+            {"id": "msg_1", "to": "+15551234567", "text": "a", "status": "sent"},
+            {"id": "msg_2", "to": "+15551234567", "text": "b", "status": "sent"}
+        ],
+        "count": 2
+    }))
+    .unwrap();
+
+    let mut ids = Vec::new();
+    for message in &messages {
+        ids.push(message.id.as_str());
+    }
+    assert_eq!(ids, ["msg_1", "msg_2"]);
+    // Still usable after the borrowing loop
+    assert_eq!(messages.len(), 2);
+
+    let scheduled: ScheduledMessageList = serde_json::from_value(json!({
+        "data": [{
+            "id": "sched_1",
+            "to": "+15551234567",
+            "text": "Reminder",
+            "scheduledAt": "2025-01-20T10:00:00Z",
+            "status": "scheduled"
+        }],
+        "count": 1
+    }))
+    .unwrap();
+    assert_eq!((&scheduled).into_iter().count(), 1);
+
+    let batches: BatchList = serde_json::from_value(json!({"data": []})).unwrap();
+    assert_eq!((&batches).into_iter().count(), 0);
+}
+
+// ==================== List Pagination Tests ====================
+
+#[test]
+fn test_message_list_reads_pagination_echo() {
+    let list: MessageList = serde_json::from_value(json!({
+        "data": [
+            {"id": "msg_1", "to": "+15551234567", "text": "Hi", "status": "sent"},
+            {"id": "msg_2", "to": "+15551234567", "text": "Hi", "status": "sent"}
+        ],
+        "count": 5,
+        "limit": 2,
+        "offset": 2
+    }))
+    .unwrap();
+
+    assert_eq!(list.limit, Some(2));
+    assert_eq!(list.offset, Some(2));
+    assert!(list.has_more());
+    assert_eq!(list.next_offset(), Some(4));
+}
+
+#[test]
+fn test_list_has_more_on_last_page() {
+    let list: ScheduledMessageList = serde_json::from_value(json!({
+        "data": [],
+        "count": 4,
+        "limit": 2,
+        "offset": 4
+    }))
+    .unwrap();
+
+    assert!(!list.has_more());
+    assert_eq!(list.next_offset(), None);
+}
+
+#[test]
+fn test_list_has_more_without_count() {
+    let full: BatchList = serde_json::from_value(json!({
+        "data": [{"batchId": "b1", "status": "completed", "total": 1, "queued": 0, "sent": 1, "failed": 0}],
+        "limit": 1
+    }))
+    .unwrap();
+    assert!(full.has_more());
+    assert_eq!(full.next_offset(), Some(1));
+
+    let bare: BatchList = serde_json::from_value(json!({"data": []})).unwrap();
+    assert_eq!(bare.limit, None);
+    assert_eq!(bare.offset, None);
+    assert!(!bare.has_more());
+}
@@ -0,0 +1,185 @@
+mod common;
+
+use common::{create_test_client, mock_send_success, setup_mock_server};
+use sendly::{Error, ListOptOutsOptions};
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+fn opt_out_client(base_url: &str) -> sendly::Sendly {
+    let config = sendly::SendlyConfig::new()
+        .base_url(base_url)
+        .max_retries(0)
+        .check_opt_outs(true);
+
+    sendly::Sendly::with_config(common::TEST_API_KEY, config)
+}
+
+// ==================== add() Tests ====================
+
+#[tokio::test]
+async fn test_add_opt_out() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/opt-outs"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "phone": "+15551234567",
+            "source": "api",
+            "createdAt": "2025-01-15T10:00:00Z"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let opt_out = client.opt_outs().add("+15551234567").await.unwrap();
+
+    assert_eq!(opt_out.phone, "+15551234567");
+    assert_eq!(opt_out.source.as_deref(), Some("api"));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["phone"], "+15551234567");
+}
+
+#[tokio::test]
+async fn test_add_opt_out_invalid_phone() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.opt_outs().add("5551234567").await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+// ==================== check() Tests ====================
+
+#[tokio::test]
+async fn test_check_opted_out() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/opt-outs/%2B15551234567"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "phone": "+15551234567"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    assert!(client.opt_outs().check("+15551234567").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_check_not_opted_out() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/opt-outs/%2B15551234567"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    assert!(!client.opt_outs().check("+15551234567").await.unwrap());
+}
+
+// ==================== remove() Tests ====================
+
+#[tokio::test]
+async fn test_remove_opt_out() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/opt-outs/%2B15551234567"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.opt_outs().remove("+15551234567").await;
+
+    assert!(result.is_ok());
+}
+
+// ==================== list() Tests ====================
+
+#[tokio::test]
+async fn test_list_opt_outs() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/opt-outs"))
+        .and(query_param("limit", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [
+                { "phone": "+15551234567", "reason": "STOP" },
+                { "phone": "+15559876543" }
+            ],
+            "count": 2
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let list = client
+        .opt_outs()
+        .list(ListOptOutsOptions::new().limit(10))
+        .await
+        .unwrap();
+
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.data[0].reason.as_deref(), Some("STOP"));
+}
+
+// ==================== check_opt_outs Tests ====================
+
+#[tokio::test]
+async fn test_send_rejects_opted_out_recipient() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/opt-outs/%2B15551234567"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "phone": "+15551234567"
+        })))
+        .mount(&mock_server)
+        .await;
+    mock_send_success().expect(0).mount(&mock_server).await;
+
+    let client = opt_out_client(&mock_server.uri());
+
+    let result = client.messages().send_to("+15551234567", "Hello").await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+}
+
+#[tokio::test]
+async fn test_send_allows_recipient_not_opted_out() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/opt-outs/%2B15551234567"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Not found"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_send_success().expect(1).mount(&mock_server).await;
+
+    let client = opt_out_client(&mock_server.uri());
+
+    let result = client.messages().send_to("+15551234567", "Hello").await;
+
+    assert!(result.is_ok());
+}
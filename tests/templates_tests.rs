@@ -0,0 +1,160 @@
+mod common;
+
+use common::{create_test_client, setup_mock_server};
+use sendly::{render_template, render_template_with, CreateTemplateRequest, Error, TemplateError};
+use serde_json::json;
+use std::collections::HashMap;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+// ==================== create() Tests ====================
+
+#[tokio::test]
+async fn test_create_template() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/verify/templates"))
+        .and(body_json(json!({
+            "name": "Order shipped",
+            "body": "Hi {{name}}, order {{order}} has shipped",
+            "locale": "en"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "tpl_123",
+            "name": "Order shipped",
+            "body": "Hi {{name}}, order {{order}} has shipped",
+            "type": "custom",
+            "locale": "en",
+            "variables": ["name", "order"],
+            "isPublished": false,
+            "createdAt": "2025-01-15T10:00:00Z"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let template = client
+        .templates()
+        .create(
+            CreateTemplateRequest::new("Order shipped", "Hi {{name}}, order {{order}} has shipped")
+                .locale("en"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(template.id, "tpl_123");
+    assert!(template.is_custom());
+    assert_eq!(template.variables, vec!["name", "order"]);
+    assert_eq!(template.created_at.as_deref(), Some("2025-01-15T10:00:00Z"));
+}
+
+// ==================== render() Tests ====================
+
+#[tokio::test]
+async fn test_render_template() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/verify/templates/tpl_123/render"))
+        .and(body_json(json!({
+            "variables": { "name": "Ada", "order": "1042" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "text": "Hi Ada, order 1042 has shipped",
+            "segments": 1,
+            "encoding": "GSM-7",
+            "characterCount": 30
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let mut variables = HashMap::new();
+    variables.insert("name".to_string(), "Ada".to_string());
+    variables.insert("order".to_string(), "1042".to_string());
+
+    let rendered = client
+        .templates()
+        .render("tpl_123", variables)
+        .await
+        .unwrap();
+
+    assert_eq!(rendered.text, "Hi Ada, order 1042 has shipped");
+    assert_eq!(rendered.segments, 1);
+    assert_eq!(rendered.character_count, Some(30));
+}
+
+// ==================== render_template() Tests ====================
+
+fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_render_template_substitutes() {
+    let body = "Hi {{name}}, your code is {{ code }}";
+
+    let text = render_template(body, &vars(&[("name", "Ada"), ("code", "123456")])).unwrap();
+
+    assert_eq!(text, "Hi Ada, your code is 123456");
+}
+
+#[test]
+fn test_render_template_missing_var() {
+    let body = "Hi {{name}}, your code is {{code}}";
+
+    let strict = render_template(body, &vars(&[("name", "Ada")]));
+    assert_eq!(
+        strict,
+        Err(TemplateError::MissingVariable("code".to_string()))
+    );
+
+    let lenient = render_template_with(body, &vars(&[("name", "Ada")]), false).unwrap();
+    assert_eq!(lenient, "Hi Ada, your code is {{code}}");
+}
+
+#[test]
+fn test_render_template_extra_var() {
+    let body = "Hi {{name}}";
+    let variables = vars(&[("name", "Ada"), ("unused", "x")]);
+
+    assert_eq!(
+        render_template(body, &variables),
+        Err(TemplateError::UnusedVariable("unused".to_string()))
+    );
+    assert_eq!(
+        render_template_with(body, &variables, false).unwrap(),
+        "Hi Ada"
+    );
+}
+
+#[test]
+fn test_render_template_escaping() {
+    let body = r"Use \{\{name}} to insert a name, {{name}}";
+
+    let text = render_template(body, &vars(&[("name", "Ada")])).unwrap();
+
+    assert_eq!(text, "Use {{name}} to insert a name, Ada");
+}
+
+#[test]
+fn test_render_template_unterminated() {
+    let result = render_template("Hi {{name", &vars(&[("name", "Ada")]));
+
+    assert_eq!(result, Err(TemplateError::Unterminated(3)));
+}
+
+#[test]
+fn test_render_template_error_converts_to_validation() {
+    let err: Error = TemplateError::MissingVariable("code".to_string()).into();
+
+    assert!(matches!(err, Error::Validation { .. }));
+}
@@ -0,0 +1,144 @@
+use sendly::validate::{
+    is_valid_phone, validate_metadata, validate_metadata_with_limits, validate_phone,
+    MetadataLimits, MAX_METADATA_BYTES, MAX_METADATA_KEYS, MAX_METADATA_VALUE_LENGTH,
+};
+use sendly::Error;
+use serde_json::json;
+use std::collections::HashMap;
+
+// ==================== is_valid_phone() Tests ====================
+
+#[test]
+fn test_is_valid_phone_accepts_e164() {
+    for phone in [
+        "+15551234567",
+        "+447911123456",
+        "+4915112345678",
+        "+861012345678",
+        "+12",
+        "+123456789012345",
+    ] {
+        assert!(is_valid_phone(phone), "{} should be valid", phone);
+    }
+}
+
+#[test]
+fn test_is_valid_phone_rejects_malformed() {
+    for phone in [
+        "",
+        "+",
+        "15551234567",
+        "+05551234567",
+        "+1 555 123 4567",
+        "+1-555-123-4567",
+        "(555) 123-4567",
+        "+1234567890123456",
+        "+1555123456a",
+        " +15551234567",
+        "+15551234567\n",
+    ] {
+        assert!(!is_valid_phone(phone), "{:?} should be invalid", phone);
+    }
+}
+
+// ==================== validate_phone() Tests ====================
+
+#[test]
+fn test_validate_phone() {
+    assert!(validate_phone("+15551234567").is_ok());
+
+    match validate_phone("555-1234") {
+        Err(Error::Validation { message }) => assert!(message.contains("E.164")),
+        other => panic!("Expected Validation error, got: {:?}", other),
+    }
+}
+
+// ==================== validate_metadata() Tests ====================
+
+fn metadata_with_keys(count: usize) -> HashMap<String, serde_json::Value> {
+    (0..count)
+        .map(|i| (format!("key_{}", i), json!(i)))
+        .collect()
+}
+
+#[test]
+fn test_validate_metadata_accepts_within_limits() {
+    let mut metadata = metadata_with_keys(MAX_METADATA_KEYS - 1);
+    metadata.insert(
+        "note".to_string(),
+        json!("x".repeat(MAX_METADATA_VALUE_LENGTH)),
+    );
+
+    assert!(validate_metadata(&metadata).is_ok());
+}
+
+#[test]
+fn test_validate_metadata_too_many_keys() {
+    let metadata = metadata_with_keys(MAX_METADATA_KEYS + 1);
+
+    match validate_metadata(&metadata).unwrap_err() {
+        Error::Validation { message } => {
+            assert!(message.contains(&format!("maximum {}", MAX_METADATA_KEYS)));
+        }
+        other => panic!("Expected Validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_metadata_value_too_long_names_key() {
+    let metadata = HashMap::from([
+        ("order_id".to_string(), json!("ord_42")),
+        (
+            "note".to_string(),
+            json!("x".repeat(MAX_METADATA_VALUE_LENGTH + 1)),
+        ),
+    ]);
+
+    match validate_metadata(&metadata).unwrap_err() {
+        Error::Validation { message } => assert!(message.contains("'note'")),
+        other => panic!("Expected Validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_metadata_total_size() {
+    // Every key and value is within its own limit, but the map is ~8KB
+    let metadata: HashMap<String, serde_json::Value> = (0..MAX_METADATA_KEYS)
+        .map(|i| (format!("key_{}", i), json!("x".repeat(400))))
+        .collect();
+
+    match validate_metadata(&metadata).unwrap_err() {
+        Error::Validation { message } => {
+            assert!(message.contains(&format!("maximum {}", MAX_METADATA_BYTES)));
+        }
+        other => panic!("Expected Validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_metadata_measures_non_string_values_as_json() {
+    let limits = MetadataLimits {
+        max_value_length: 10,
+        ..MetadataLimits::default()
+    };
+    let metadata = HashMap::from([("items".to_string(), json!([1, 2, 3, 4, 5, 6]))]);
+
+    match validate_metadata_with_limits(&metadata, &limits).unwrap_err() {
+        Error::Validation { message } => assert!(message.contains("'items'")),
+        other => panic!("Expected Validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_metadata_key_too_long() {
+    let limits = MetadataLimits {
+        max_key_length: 5,
+        ..MetadataLimits::default()
+    };
+    let metadata = HashMap::from([("customer".to_string(), json!("c_1"))]);
+
+    match validate_metadata_with_limits(&metadata, &limits).unwrap_err() {
+        Error::Validation { message } => assert!(message.contains("'customer'")),
+        other => panic!("Expected Validation error, got {:?}", other),
+    }
+}
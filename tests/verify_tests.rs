@@ -0,0 +1,180 @@
+mod common;
+
+use common::{create_test_client, setup_mock_server};
+use sendly::{Channel, DeliveryStatus, Error, SendVerificationRequest, SendVerificationResponse};
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+// ==================== SendVerificationRequest Tests ====================
+
+#[test]
+fn test_send_verification_request_builder_sets_every_field() {
+    let mut metadata = HashMap::new();
+    metadata.insert("userId".to_string(), json!("usr_123"));
+
+    let request = SendVerificationRequest::new("+15551234567")
+        .channel(Channel::Whatsapp)
+        .code_length(8)
+        .expires_in(300)
+        .max_attempts(5)
+        .template_id("tpl_abc")
+        .profile_id("prof_abc")
+        .app_name("Acme")
+        .locale("de")
+        .metadata(metadata);
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "to": "+15551234567",
+            "channel": "whatsapp",
+            "codeLength": 8,
+            "expiresIn": 300,
+            "maxAttempts": 5,
+            "templateId": "tpl_abc",
+            "profileId": "prof_abc",
+            "appName": "Acme",
+            "locale": "de",
+            "metadata": {"userId": "usr_123"}
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_send_verification_invalid_phone() {
+    let mock_server = setup_mock_server().await;
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .verify()
+        .send(SendVerificationRequest::new("555-123-4567"))
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation { .. })));
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+// ==================== SendVerificationResponse Tests ====================
+
+fn send_response(sandbox: bool) -> SendVerificationResponse {
+    serde_json::from_value(json!({
+        "verification": {
+            "id": "ver_1",
+            "status": "pending",
+            "phone": "+15551234567",
+            "deliveryStatus": "pending",
+            "expiresAt": "2025-01-15T10:10:00Z",
+            "createdAt": "2025-01-15T10:00:00Z",
+            "sandbox": sandbox
+        },
+        "code": "123456"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_sandbox_code_in_sandbox() {
+    let response = send_response(true);
+
+    assert_eq!(response.sandbox_code(), Some("123456"));
+}
+
+#[test]
+fn test_sandbox_code_hidden_for_live() {
+    let response = send_response(false);
+
+    assert_eq!(response.sandbox_code(), None);
+}
+
+// ==================== wait_until_delivered() Tests ====================
+
+fn verification(delivery_status: &str) -> serde_json::Value {
+    json!({
+        "id": "ver_1",
+        "status": "pending",
+        "phone": "+15551234567",
+        "deliveryStatus": delivery_status,
+        "channel": "whatsapp",
+        "expiresAt": "2025-01-15T10:10:00Z",
+        "createdAt": "2025-01-15T10:00:00Z"
+    })
+}
+
+#[tokio::test]
+async fn test_wait_until_delivered_pending_then_delivered() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/verify/ver_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(verification("pending")))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/verify/ver_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(verification("delivered")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let verification = client
+        .verify()
+        .wait_until_delivered("ver_1", Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(verification.delivery_status, DeliveryStatus::Delivered);
+}
+
+#[tokio::test]
+async fn test_wait_until_delivered_returns_failed_delivery() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/verify/ver_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(verification("failed")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let verification = client
+        .verify()
+        .wait_until_delivered("ver_1", Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(verification.delivery_status, DeliveryStatus::Failed);
+}
+
+#[tokio::test]
+async fn test_wait_until_delivered_times_out() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/verify/ver_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(verification("sent")))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .verify()
+        .wait_until_delivered(
+            "ver_1",
+            Duration::from_millis(20),
+            Duration::from_millis(50),
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::Timeout)));
+}
@@ -0,0 +1,506 @@
+mod common;
+
+use common::{create_test_client, setup_mock_server};
+use futures::StreamExt;
+use sendly::{
+    CreateWebhookRequest, Error, ListDeliveriesOptions, ListWebhooksOptions, WebhookMode,
+};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, path_regex, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+fn delivery(id: &str, success: bool) -> serde_json::Value {
+    json!({
+        "id": id,
+        "webhookId": "whk_abc123",
+        "eventType": "message.delivered",
+        "httpStatus": if success { 200 } else { 500 },
+        "success": success,
+        "attemptNumber": 1,
+        "responseTimeMs": 120,
+        "createdAt": "2025-01-15T10:00:00Z"
+    })
+}
+
+// ==================== create_with_options() Tests ====================
+
+#[tokio::test]
+async fn test_create_with_options_sends_mode_and_api_version() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/webhooks"))
+        .and(body_json(json!({
+            "url": "https://example.com/hook",
+            "events": ["message.delivered"],
+            "mode": "live",
+            "apiVersion": "2024-01-01"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "whk_abc123",
+            "url": "https://example.com/hook",
+            "mode": "live",
+            "secret": "whsec_abc"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let request = CreateWebhookRequest::new("https://example.com/hook", vec!["message.delivered"])
+        .mode(WebhookMode::Live)
+        .api_version("2024-01-01");
+    let response = client
+        .webhooks()
+        .create_with_options(request)
+        .await
+        .unwrap();
+
+    assert_eq!(response.get_webhook().unwrap().id, "whk_abc123");
+    assert_eq!(response.secret.expose(), "whsec_abc");
+}
+
+#[tokio::test]
+async fn test_create_omits_mode_and_api_version() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/webhooks"))
+        .and(body_json(json!({
+            "url": "https://example.com/hook",
+            "events": ["message.failed"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "whk_abc123",
+            "url": "https://example.com/hook",
+            "secret": "whsec_abc"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    client
+        .webhooks()
+        .create("https://example.com/hook", vec!["message.failed"])
+        .await
+        .unwrap();
+}
+
+#[test]
+fn test_webhook_mode_as_str() {
+    assert_eq!(WebhookMode::All.as_str(), "all");
+    assert_eq!(WebhookMode::Test.to_string(), "test");
+    assert_eq!(
+        serde_json::to_value(WebhookMode::Live).unwrap(),
+        json!(WebhookMode::Live.as_str())
+    );
+}
+
+// ==================== list() Tests ====================
+
+#[tokio::test]
+async fn test_list_with_options_sends_filters() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks"))
+        .and(query_param("event", "message.failed"))
+        .and(query_param("active", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "webhooks": [{"id": "whk_abc123", "url": "https://example.com/hook"}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let webhooks = client
+        .webhooks()
+        .list_with_options(
+            ListWebhooksOptions::new()
+                .event("message.failed")
+                .active(true),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0].id, "whk_abc123");
+}
+
+#[tokio::test]
+async fn test_list_sends_no_filters() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"data": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let webhooks = client.webhooks().list().await.unwrap();
+    assert!(webhooks.is_empty());
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests[0].url.query(), None);
+}
+
+// ==================== iter_deliveries() Tests ====================
+
+#[tokio::test]
+async fn test_iter_deliveries_pagination() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_abc123/deliveries"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "deliveries": [delivery("del_1", true), delivery("del_2", false)],
+            "total": 3,
+            "hasMore": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_abc123/deliveries"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "deliveries": [delivery("del_3", true)],
+            "total": 3,
+            "hasMore": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let webhooks = client.webhooks();
+    let stream =
+        webhooks.iter_deliveries("whk_abc123", Some(ListDeliveriesOptions::new().limit(2)));
+    futures::pin_mut!(stream);
+    let mut deliveries = Vec::new();
+
+    while let Some(result) = stream.next().await {
+        deliveries.push(result.unwrap());
+    }
+
+    assert_eq!(deliveries.len(), 3);
+    assert_eq!(deliveries[0].id, "del_1");
+    assert_eq!(deliveries[2].id, "del_3");
+}
+
+#[tokio::test]
+async fn test_iter_deliveries_success_filter() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_abc123/deliveries"))
+        .and(query_param("success", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "deliveries": [delivery("del_2", false)],
+            "total": 1,
+            "hasMore": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let webhooks = client.webhooks();
+    let stream = webhooks.iter_deliveries(
+        "whk_abc123",
+        Some(ListDeliveriesOptions::new().success(false)),
+    );
+    futures::pin_mut!(stream);
+    let mut deliveries = Vec::new();
+
+    while let Some(result) = stream.next().await {
+        deliveries.push(result.unwrap());
+    }
+
+    assert_eq!(deliveries.len(), 1);
+    assert!(!deliveries[0].success);
+}
+
+#[tokio::test]
+async fn test_iter_deliveries_date_filter_kept_across_pages() {
+    let mock_server = setup_mock_server().await;
+
+    for (offset, ids) in [("0", vec!["del_1", "del_2"]), ("2", vec!["del_3"])] {
+        let deliveries: Vec<_> = ids.into_iter().map(|id| delivery(id, false)).collect();
+        Mock::given(method("GET"))
+            .and(path("/webhooks/whk_abc123/deliveries"))
+            .and(query_param("limit", "2"))
+            .and(query_param("offset", offset))
+            .and(query_param("success", "false"))
+            .and(query_param("from", "2025-01-15T00:00:00Z"))
+            .and(query_param("to", "2025-01-16T00:00:00Z"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "deliveries": deliveries,
+                "total": 3,
+                "hasMore": offset == "0"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = create_test_client(&mock_server.uri());
+
+    let webhooks = client.webhooks();
+    let stream = webhooks.iter_deliveries(
+        "whk_abc123",
+        Some(
+            ListDeliveriesOptions::new()
+                .limit(2)
+                .success(false)
+                .from("2025-01-15T00:00:00Z")
+                .to("2025-01-16T00:00:00Z"),
+        ),
+    );
+    futures::pin_mut!(stream);
+    let mut ids = Vec::new();
+
+    while let Some(result) = stream.next().await {
+        ids.push(result.unwrap().id);
+    }
+
+    assert_eq!(ids, vec!["del_1", "del_2", "del_3"]);
+}
+
+// ==================== retry_failed() Tests ====================
+
+#[tokio::test]
+async fn test_retry_failed_retries_each_delivery() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_abc123/deliveries"))
+        .and(query_param("success", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "deliveries": [
+                delivery("del_1", false),
+                delivery("del_2", false),
+                delivery("del_3", false)
+            ],
+            "total": 3,
+            "hasMore": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path_regex(
+            r"^/webhooks/whk_abc123/deliveries/del_[12]/retry$",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "delivery": delivery("del_retry", true)
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    // A failing retry must not abort the others
+    Mock::given(method("POST"))
+        .and(path("/webhooks/whk_abc123/deliveries/del_3/retry"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "error": "Internal server error"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .webhooks()
+        .retry_failed("whk_abc123", None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.retried, 3);
+    assert_eq!(result.succeeded, 2);
+    assert_eq!(result.failed, 1);
+}
+
+#[tokio::test]
+async fn test_retry_failed_sends_since_as_from() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_abc123/deliveries"))
+        .and(query_param("success", "false"))
+        .and(query_param("from", "2025-01-15T00:00:00Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "deliveries": [],
+            "total": 0,
+            "hasMore": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client
+        .webhooks()
+        .retry_failed("whk_abc123", Some("2025-01-15T00:00:00Z".to_string()))
+        .await
+        .unwrap();
+
+    assert_eq!(result.retried, 0);
+}
+
+// ==================== reset_circuit() Tests ====================
+
+#[tokio::test]
+async fn test_reset_circuit() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/webhooks/whk_abc123/reset-circuit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "webhook": {
+                "id": "whk_abc123",
+                "url": "https://example.com/hook",
+                "circuitState": "closed",
+                "failureCount": 0
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let webhook = client.webhooks().reset_circuit("whk_abc123").await.unwrap();
+
+    assert!(!webhook.is_circuit_open());
+    assert_eq!(webhook.failure_count, 0);
+}
+
+#[tokio::test]
+async fn test_reset_circuit_not_found() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/webhooks/whk_missing/reset-circuit"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Webhook not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.webhooks().reset_circuit("whk_missing").await;
+
+    assert!(matches!(result, Err(Error::NotFound { .. })));
+}
+
+// ==================== get() / get_delivery() Tests ====================
+
+#[tokio::test]
+async fn test_get_webhook_wrapped_and_flat() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_wrapped"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "webhook": {"id": "whk_wrapped", "url": "https://example.com/hook"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_flat"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "whk_flat",
+            "url": "https://example.com/hook",
+            "isActive": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let wrapped = client.webhooks().get("whk_wrapped").await.unwrap();
+    assert_eq!(wrapped.id, "whk_wrapped");
+    assert!(wrapped.is_active);
+
+    let flat = client.webhooks().get("whk_flat").await.unwrap();
+    assert_eq!(flat.id, "whk_flat");
+    assert!(!flat.is_active);
+}
+
+#[tokio::test]
+async fn test_get_webhook_unexpected_shape() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_abc123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "unexpected": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.webhooks().get("whk_abc123").await;
+
+    match result.unwrap_err() {
+        Error::Json(e) => assert!(e.to_string().contains("webhook")),
+        other => panic!("Expected Json error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_list_webhooks_unexpected_shape() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "endpoints": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.webhooks().list().await;
+
+    match result.unwrap_err() {
+        Error::Json(e) => assert!(e.to_string().contains("webhook list")),
+        other => panic!("Expected Json error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_get_delivery_unexpected_shape() {
+    let mock_server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/webhooks/whk_abc123/deliveries/del_1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server.uri());
+
+    let result = client.webhooks().get_delivery("whk_abc123", "del_1").await;
+
+    assert!(matches!(result, Err(Error::Json(_))));
+}